@@ -0,0 +1,112 @@
+//! Audacity label track import and export.
+//!
+//! Audacity stores label tracks as plain text with one tab-separated `start\tend\tlabel` line per
+//! label, times in seconds. An IntervalTier maps onto this one-to-one; a PointTier is written with
+//! equal start and end times, which Audacity shows as point labels.
+//!
+//! ## Format
+//! - Times are written with six decimal places, as Audacity does.
+//! - Line breaks inside a label are written as spaces, since each label must stay on one line.
+//! - On import, blank lines and Audacity's spectral-selection lines (starting with `\`) are skipped,
+//!   and a missing label field is read as an empty label.
+//! - Imported tiers span from `0` to the latest label end.
+
+use crate::types::{Interval, Point, TextGridError, Tier, TierType};
+
+/// Writes a tier as an Audacity label track.
+///
+/// # Arguments
+/// * `tier` - The tier to export.
+///
+/// # Returns
+/// Returns the label track text, one line per interval or point, each ending in `\n`.
+pub(crate) fn write_labels(tier: &Tier) -> String {
+    let mut labels = String::new();
+    match tier.tier_type {
+        TierType::IntervalTier => {
+            for interval in &tier.intervals {
+                labels.push_str(&format!("{:.6}\t{:.6}\t{}\n", interval.xmin, interval.xmax, single_line(&interval.text)));
+            }
+        }
+        TierType::PointTier => {
+            for point in &tier.points {
+                labels.push_str(&format!("{:.6}\t{:.6}\t{}\n", point.time, point.time, single_line(&point.mark)));
+            }
+        }
+    }
+    labels
+}
+
+/// Replaces each line break in a label with a space.
+fn single_line(label: &str) -> String {
+    label.replace("\r\n", "\n").replace(['\n', '\r'], " ")
+}
+
+/// Reads an Audacity label track into a tier.
+///
+/// # Arguments
+/// * `name` - Name of the new tier.
+/// * `text` - The label track text.
+/// * `as_points` - If `true`, builds a PointTier and requires every label to have equal start and end
+///   times; otherwise builds an IntervalTier.
+///
+/// # Returns
+/// Returns a `Result` containing the validated tier or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if a line is malformed, has `start > end` or a negative time, a point
+///   label has different start and end times, the track has no labels, or the tier fails validation.
+pub(crate) fn read_labels(name: &str, text: &str, as_points: bool) -> Result<Tier, TextGridError> {
+    let mut intervals = Vec::new();
+    let mut points = Vec::new();
+    let mut xmax: f64 = 0.0;
+    for (number, line) in text.lines().enumerate().map(|(i, line)| (i + 1, line)) {
+        if line.trim().is_empty() || line.starts_with('\\') {
+            continue;
+        }
+        let mut fields = line.splitn(3, '\t');
+        let start = parse_time(fields.next(), number)?;
+        let end = parse_time(fields.next(), number)?;
+        let label = fields.next().unwrap_or("").to_string();
+        if start > end {
+            return Err(TextGridError::Format(format!("Line {}: start {} is after end {}", number, start, end)));
+        }
+        if start < 0.0 {
+            return Err(TextGridError::Format(format!("Line {}: negative time {}", number, start)));
+        }
+        xmax = xmax.max(end);
+        if as_points {
+            if start != end {
+                return Err(TextGridError::Format(format!("Line {}: point label spans {} to {}", number, start, end)));
+            }
+            points.push(Point { time: start, mark: label });
+        } else {
+            intervals.push(Interval { xmin: start, xmax: end, text: label, score: None });
+        }
+    }
+    if intervals.is_empty() && points.is_empty() {
+        return Err(TextGridError::Format("Label track has no labels".into()));
+    }
+
+    let tier = Tier {
+        name: name.to_string(),
+        tier_type: if as_points { TierType::PointTier } else { TierType::IntervalTier },
+        xmin: 0.0,
+        xmax,
+        intervals,
+        points,
+    };
+    tier.validate()?;
+    Ok(tier)
+}
+
+/// Parses one time field of a label line.
+fn parse_time(field: Option<&str>, number: usize) -> Result<f64, TextGridError> {
+    let field = field.ok_or_else(|| TextGridError::Format(format!("Line {}: expected start and end times", number)))?;
+    field
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|time| time.is_finite())
+        .ok_or_else(|| TextGridError::Format(format!("Line {}: invalid time '{}'", number, field)))
+}
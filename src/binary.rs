@@ -10,24 +10,19 @@
 //! - Stores time values as 64-bit floats (`f64`), lengths as 16-bit or 32-bit integers, and text as UTF-8 strings with length prefixes.
 //!
 //! ## Usage
-//! ```rust
+//! ```rust,no_run
 //! use textgrid::{TextGrid, Tier, TierType, Interval, write_binary, read_binary};
 //!
 //! fn main() -> Result<(), textgrid::TextGridError> {
 //!     // Create a simple TextGrid
 //!     let mut tg = TextGrid::new(0.0, 10.0)?;
-//!     let tier = Tier {
-//!         name: "words".to_string(),
-//!         tier_type: TierType::IntervalTier,
-//!         xmin: 0.0,
-//!         xmax: 10.0,
-//!         intervals: vec![Interval {
+//!     let tier = Tier::new("words", TierType::IntervalTier, 0.0, 10.0)?
+//!         .with_intervals(vec![Interval {
 //!             xmin: 1.0,
 //!             xmax: 2.0,
 //!             text: "hello".to_string(),
-//!         }],
-//!         points: vec![],
-//!     };
+//!             score: None,
+//!         }]);
 //!     tg.add_tier(tier)?;
 //!
 //!     // Write to a binary file
@@ -35,7 +30,7 @@
 //!
 //!     // Read it back
 //!     let read_tg = read_binary("output.TextGrid")?;
-//!     assert_eq!(read_tg.tiers[0].intervals[0].text, "hello");
+//!     assert_eq!(read_tg.tiers()[0].intervals()[0].text, "hello");
 //!     Ok(())
 //! }
 //! ```
@@ -45,6 +40,15 @@ use std::fs::File;
 use std::io::{Read, Write, BufReader, BufWriter};
 use std::path::Path;
 
+/// Options controlling how a `TextGrid` is written in binary format.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BinaryWriteOptions {
+    /// If set, interval boundaries within this distance of their neighbor (or of the tier bounds)
+    /// are written as identical values, so rounding noise does not survive into the file.
+    /// Disabled by default to avoid silently changing stored precision.
+    pub snap_epsilon: Option<f64>,
+}
+
 /// Reads a Praat `.TextGrid` file from the binary format.
 ///
 /// # Arguments
@@ -58,41 +62,63 @@ use std::path::Path;
 /// - `TextGridError::Format` if the file does not match the Praat binary format (e.g., wrong header, invalid class, or malformed data).
 ///
 /// # Examples
-/// ```rust
+/// ```rust,no_run
 /// let tg = textgrid::read_binary("test.TextGrid").unwrap();
-/// assert_eq!(tg.tiers.len(), 1); // Assuming test.TextGrid has one tier
+/// assert_eq!(tg.tiers().len(), 1); // Assuming test.TextGrid has one tier
 /// ```
 pub fn read_binary<P: AsRef<Path>>(path: P) -> Result<TextGrid, TextGridError> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
     let mut buffer = Vec::new();
     reader.read_to_end(&mut buffer)?;
+    parse_binary(&buffer)
+}
 
+/// Parses a Praat binary TextGrid from an in-memory buffer.
+///
+/// Every read is bounds-checked, so truncated or corrupt input yields an error rather than a panic.
+/// Bytes left over after the declared tiers are ignored; use [`parse_binary_with_trailing`] to see them.
+///
+/// # Arguments
+/// * `buffer` - The raw bytes of a binary `.TextGrid` file.
+///
+/// # Returns
+/// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the data does not match the Praat binary format or ends prematurely.
+pub(crate) fn parse_binary(buffer: &[u8]) -> Result<TextGrid, TextGridError> {
+    parse_binary_with_trailing(buffer).map(|(textgrid, _)| textgrid)
+}
+
+/// Parses a Praat binary TextGrid from an in-memory buffer, also returning any bytes after the declared tiers.
+///
+/// # Arguments
+/// * `buffer` - The raw bytes of a binary `.TextGrid` file.
+///
+/// # Returns
+/// Returns a `Result` containing the parsed `TextGrid` and the unread rest of `buffer`, or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the data does not match the Praat binary format or ends prematurely.
+pub(crate) fn parse_binary_with_trailing(buffer: &[u8]) -> Result<(TextGrid, &[u8]), TextGridError> {
     let mut cursor = 0;
-    if &buffer[cursor..cursor + 12] != b"ooBinaryFile" {
+    if read_exact(buffer, &mut cursor, 12)? != b"ooBinaryFile" {
         return Err(TextGridError::Format("Not a Praat binary TextGrid".into()));
     }
-    cursor += 12;
 
-    let obj_len = u16::from_le_bytes(buffer[cursor..cursor + 2].try_into().unwrap()) as usize;
-    cursor += 2;
-    if &buffer[cursor..cursor + obj_len] != b"TextGrid" {
+    let obj_len = read_u16(buffer, &mut cursor)? as usize;
+    if read_exact(buffer, &mut cursor, obj_len)? != b"TextGrid" {
         return Err(TextGridError::Format("Invalid object class".into()));
     }
-    cursor += obj_len;
 
-    let xmin = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-    cursor += 8;
-    let xmax = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-    cursor += 8;
-    let size = u32::from_le_bytes(buffer[cursor..cursor + 4].try_into().unwrap()) as usize;
-    cursor += 4;
+    let xmin = read_f64(buffer, &mut cursor)?;
+    let xmax = read_f64(buffer, &mut cursor)?;
+    let size = read_u32(buffer, &mut cursor)? as usize;
 
-    let mut tiers = Vec::with_capacity(size);
+    let mut tiers = Vec::new();
     for _ in 0..size {
-        let class_len = u16::from_le_bytes(buffer[cursor..cursor + 2].try_into().unwrap()) as usize;
-        cursor += 2;
-        let class = String::from_utf8(buffer[cursor..cursor + class_len].to_vec())?;
+        let class = read_string(buffer, &mut cursor)?;
         let tier_type = if class == "IntervalTier" {
             TierType::IntervalTier
         } else if class == "TextTier" {
@@ -100,45 +126,27 @@ pub fn read_binary<P: AsRef<Path>>(path: P) -> Result<TextGrid, TextGridError> {
         } else {
             return Err(TextGridError::Format("Unknown tier type".into()));
         };
-        cursor += class_len;
-
-        let name_len = u16::from_le_bytes(buffer[cursor..cursor + 2].try_into().unwrap()) as usize;
-        cursor += 2;
-        let name = String::from_utf8(buffer[cursor..cursor + name_len].to_vec())?;
-        cursor += name_len;
-
-        let tier_xmin = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-        cursor += 8;
-        let tier_xmax = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-        cursor += 8;
 
-        let count = u32::from_le_bytes(buffer[cursor..cursor + 4].try_into().unwrap()) as usize;
-        cursor += 4;
+        let name = read_string(buffer, &mut cursor)?;
+        let tier_xmin = read_f64(buffer, &mut cursor)?;
+        let tier_xmax = read_f64(buffer, &mut cursor)?;
+        let count = read_u32(buffer, &mut cursor)? as usize;
 
         let mut intervals = Vec::new();
         let mut points = Vec::new();
         match tier_type {
             TierType::IntervalTier => {
                 for _ in 0..count {
-                    let xmin = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-                    cursor += 8;
-                    let xmax = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-                    cursor += 8;
-                    let text_len = u16::from_le_bytes(buffer[cursor..cursor + 2].try_into().unwrap()) as usize;
-                    cursor += 2;
-                    let text = String::from_utf8(buffer[cursor..cursor + text_len].to_vec())?;
-                    cursor += text_len;
-                    intervals.push(Interval { xmin, xmax, text });
+                    let xmin = read_f64(buffer, &mut cursor)?;
+                    let xmax = read_f64(buffer, &mut cursor)?;
+                    let text = read_string(buffer, &mut cursor)?;
+                    intervals.push(Interval { xmin, xmax, text, score: None });
                 }
             }
             TierType::PointTier => {
                 for _ in 0..count {
-                    let time = f64::from_le_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
-                    cursor += 8;
-                    let mark_len = u16::from_le_bytes(buffer[cursor..cursor + 2].try_into().unwrap()) as usize;
-                    cursor += 2;
-                    let mark = String::from_utf8(buffer[cursor..cursor + mark_len].to_vec())?;
-                    cursor += mark_len;
+                    let time = read_f64(buffer, &mut cursor)?;
+                    let mark = read_string(buffer, &mut cursor)?;
                     points.push(Point { time, mark });
                 }
             }
@@ -146,8 +154,48 @@ pub fn read_binary<P: AsRef<Path>>(path: P) -> Result<TextGrid, TextGridError> {
 
         tiers.push(Tier { name, tier_type, xmin: tier_xmin, xmax: tier_xmax, intervals, points });
     }
+    Ok((TextGrid::new(xmin, xmax)?.with_tiers(tiers), &buffer[cursor..]))
+}
+
+/// Reads `n` bytes from `buffer` at `cursor`, advancing the cursor past them.
+///
+/// # Errors
+/// Returns `TextGridError::Format` if fewer than `n` bytes remain.
+fn read_exact<'a>(buffer: &'a [u8], cursor: &mut usize, n: usize) -> Result<&'a [u8], TextGridError> {
+    let end = cursor
+        .checked_add(n)
+        .filter(|&end| end <= buffer.len())
+        .ok_or_else(|| TextGridError::Format(format!("Unexpected end of binary data at byte {}", cursor)))?;
+    let bytes = &buffer[*cursor..end];
+    *cursor = end;
+    Ok(bytes)
+}
+
+/// Reads a little-endian `u16` at `cursor`.
+fn read_u16(buffer: &[u8], cursor: &mut usize) -> Result<u16, TextGridError> {
+    let mut bytes = [0u8; 2];
+    bytes.copy_from_slice(read_exact(buffer, cursor, 2)?);
+    Ok(u16::from_le_bytes(bytes))
+}
+
+/// Reads a little-endian `u32` at `cursor`.
+fn read_u32(buffer: &[u8], cursor: &mut usize) -> Result<u32, TextGridError> {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(read_exact(buffer, cursor, 4)?);
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Reads a little-endian `f64` at `cursor`.
+fn read_f64(buffer: &[u8], cursor: &mut usize) -> Result<f64, TextGridError> {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(read_exact(buffer, cursor, 8)?);
+    Ok(f64::from_le_bytes(bytes))
+}
 
-    Ok(TextGrid::new(xmin, xmax)?.with_tiers(tiers))
+/// Reads a `u16` length-prefixed UTF-8 string at `cursor`.
+fn read_string(buffer: &[u8], cursor: &mut usize) -> Result<String, TextGridError> {
+    let len = read_u16(buffer, cursor)? as usize;
+    Ok(String::from_utf8(read_exact(buffer, cursor, len)?.to_vec())?)
 }
 
 /// Writes a `TextGrid` to a Praat `.TextGrid` file in binary format.
@@ -163,7 +211,9 @@ pub fn read_binary<P: AsRef<Path>>(path: P) -> Result<TextGrid, TextGridError> {
 /// - `TextGridError::IO` if the file cannot be created or written to.
 ///
 /// # Examples
-/// ```rust
+/// ```rust,no_run
+/// use textgrid::TextGrid;
+///
 /// let tg = TextGrid::new(0.0, 5.0).unwrap(); // Assume tiers are added
 /// textgrid::write_binary(&tg, "test.TextGrid").unwrap();
 /// ```
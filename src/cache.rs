@@ -0,0 +1,57 @@
+//! Fast internal cache serialization for TextGrid data.
+//!
+//! This module encodes a `TextGrid` to a compact byte representation using `bincode`, and decodes it
+//! back. It is intended for caching parsed grids between runs, preserving the bounds and the exact
+//! tier order. The undo/redo history is not stored.
+//!
+//! This format is **not** Praat-compatible and is not stable across crate versions; use the binary
+//! module for files that Praat must read.
+//!
+//! ## Usage
+//! ```rust
+//! use textgrid::TextGrid;
+//!
+//! fn main() -> Result<(), textgrid::TextGridError> {
+//!     let tg = TextGrid::new(0.0, 10.0)?;
+//!     let bytes = tg.to_cache_bytes()?;
+//!     let restored = TextGrid::from_cache_bytes(&bytes)?;
+//!     assert_eq!(restored.xmax, 10.0);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::types::{TextGrid, TextGridError};
+
+/// Encodes a `TextGrid` into cache bytes.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to encode.
+///
+/// # Returns
+/// Returns a `Result` containing the encoded bytes or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if encoding fails.
+pub fn encode_cache(textgrid: &TextGrid) -> Result<Vec<u8>, TextGridError> {
+    bincode::serde::encode_to_vec(textgrid, bincode::config::standard())
+        .map_err(|e| TextGridError::Format(format!("Failed to encode cache: {}", e)))
+}
+
+/// Decodes a `TextGrid` from cache bytes.
+///
+/// # Arguments
+/// * `bytes` - Bytes previously produced by [`encode_cache`].
+///
+/// # Returns
+/// Returns a `Result` containing the decoded `TextGrid` (with empty history) or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the bytes are malformed or followed by trailing data.
+pub fn decode_cache(bytes: &[u8]) -> Result<TextGrid, TextGridError> {
+    let (textgrid, read) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+        .map_err(|e| TextGridError::Format(format!("Failed to decode cache: {}", e)))?;
+    if read != bytes.len() {
+        return Err(TextGridError::Format("Trailing data after cached TextGrid".into()));
+    }
+    Ok(textgrid)
+}
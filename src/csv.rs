@@ -0,0 +1,211 @@
+//! Long-format CSV import and export for TextGrid data.
+//!
+//! This module flattens a whole `TextGrid` into a single CSV table with one row per interval or point,
+//! suitable for loading into pandas or R, and reconstructs a `TextGrid` from such a table.
+//!
+//! ## Format
+//! - Header: `tier,type,index,xmin,xmax,text,score`.
+//! - `score` holds the interval's optional score and is empty for points and unscored intervals;
+//!   tables with the older `tier,type,index,xmin,xmax,text` header are still read.
+//! - `type` is `IntervalTier` or `PointTier`; `index` is the 0-based position within the tier.
+//! - Point rows use `xmin == xmax == time` and put the mark in `text`.
+//! - Fields containing commas, quotes, or line breaks are quoted, with embedded quotes doubled.
+//! - Tiers without any intervals or points produce no rows, so they do not survive a round trip.
+//!
+//! ## Usage
+//! ```rust
+//! use textgrid::{TextGrid, Tier, TierType, Interval};
+//!
+//! fn main() -> Result<(), textgrid::TextGridError> {
+//!     let mut tg = TextGrid::new(0.0, 2.0)?;
+//!     tg.add_tier(Tier::new("words", TierType::IntervalTier, 0.0, 2.0)?
+//!         .with_intervals(vec![Interval { xmin: 0.0, xmax: 1.0, text: "hi, there".to_string(), score: None }]))?;
+//!     assert_eq!(tg.to_long_csv(), "tier,type,index,xmin,xmax,text,score\nwords,IntervalTier,0,0,1,\"hi, there\",\n");
+//!     Ok(())
+//! }
+//! ```
+
+use crate::types::{Interval, Point, TextGrid, TextGridError, Tier, TierType};
+
+/// Header row of the long CSV format.
+const HEADER: &str = "tier,type,index,xmin,xmax,text,score";
+
+/// Header row of the long CSV format before the `score` column was added.
+const LEGACY_HEADER: &str = "tier,type,index,xmin,xmax,text";
+
+/// Writes a `TextGrid` as a long-format CSV string.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to export.
+///
+/// # Returns
+/// Returns the CSV text, including the header row, with `\n` line endings.
+pub fn write_long_csv(textgrid: &TextGrid) -> String {
+    let mut csv = String::new();
+    csv.push_str(HEADER);
+    csv.push('\n');
+    for tier in &textgrid.tiers {
+        let name = escape_field(&tier.name);
+        match tier.tier_type {
+            TierType::IntervalTier => {
+                for (i, interval) in tier.intervals.iter().enumerate() {
+                    csv.push_str(&format!(
+                        "{},IntervalTier,{},{},{},{},{}\n",
+                        name,
+                        i,
+                        interval.xmin,
+                        interval.xmax,
+                        escape_field(&interval.text),
+                        interval.score.map(|score| score.to_string()).unwrap_or_default()
+                    ));
+                }
+            }
+            TierType::PointTier => {
+                for (i, point) in tier.points.iter().enumerate() {
+                    csv.push_str(&format!(
+                        "{},PointTier,{},{},{},{},\n",
+                        name,
+                        i,
+                        point.time,
+                        point.time,
+                        escape_field(&point.mark)
+                    ));
+                }
+            }
+        }
+    }
+    csv
+}
+
+/// Reads a `TextGrid` from a long-format CSV string.
+///
+/// Rows are grouped into tiers by the `tier` column, in order of first appearance, and ordered within
+/// each tier by the `index` column. Every tier spans `[xmin, xmax]`.
+///
+/// # Arguments
+/// * `csv` - CSV text with the `tier,type,index,xmin,xmax,text,score` header (or the older header without `score`).
+/// * `xmin` - Start time of the resulting TextGrid.
+/// * `xmax` - End time of the resulting TextGrid.
+///
+/// # Returns
+/// Returns a `Result` containing the reconstructed `TextGrid` or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the header is wrong, a row is malformed (reported with its 1-based row
+///   number, counting the header as row 1), or the bounds are invalid.
+pub fn read_long_csv(csv: &str, xmin: f64, xmax: f64) -> Result<TextGrid, TextGridError> {
+    let mut records = parse_records(csv)?.into_iter().enumerate();
+    let columns = match records.next() {
+        Some((_, header)) if header.join(",") == HEADER => 7,
+        Some((_, header)) if header.join(",") == LEGACY_HEADER => 6,
+        _ => return Err(TextGridError::Format(format!("Row 1: expected header '{}'", HEADER))),
+    };
+
+    let mut tiers: Vec<(Tier, Vec<usize>)> = Vec::new();
+    for (i, record) in records {
+        let row = i + 1;
+        let row_error = |message: &str| TextGridError::Format(format!("Row {}: {}", row, message));
+        if record.len() != columns {
+            return Err(row_error(&format!("expected {} columns", columns)));
+        }
+        let mut fields = record.into_iter();
+        let mut field = || fields.next().unwrap_or_default();
+        let (name, class, index, start, end, text, score) = (field(), field(), field(), field(), field(), field(), field());
+        let tier_type = match class.as_str() {
+            "IntervalTier" => TierType::IntervalTier,
+            "PointTier" | "TextTier" => TierType::PointTier,
+            _ => return Err(row_error("unknown tier type")),
+        };
+        let index: usize = index.parse().map_err(|_| row_error("invalid index"))?;
+        let start: f64 = start.parse().map_err(|_| row_error("invalid xmin"))?;
+        let end: f64 = end.parse().map_err(|_| row_error("invalid xmax"))?;
+        let score: Option<f64> = match score.as_str() {
+            "" => None,
+            score => Some(score.parse().map_err(|_| row_error("invalid score"))?),
+        };
+
+        let position = match tiers.iter().position(|(tier, _)| tier.name == name) {
+            Some(position) => position,
+            None => {
+                let tier = Tier { name, tier_type, xmin, xmax, intervals: Vec::new(), points: Vec::new() };
+                tiers.push((tier, Vec::new()));
+                tiers.len() - 1
+            }
+        };
+        let (tier, indices) = &mut tiers[position];
+        if tier.tier_type != tier_type {
+            return Err(row_error("tier type differs from earlier rows of the same tier"));
+        }
+        match tier_type {
+            TierType::IntervalTier => tier.intervals.push(Interval { xmin: start, xmax: end, text, score }),
+            TierType::PointTier => {
+                if start != end {
+                    return Err(row_error("point rows must have xmin equal to xmax"));
+                }
+                tier.points.push(Point { time: start, mark: text });
+            }
+        }
+        indices.push(index);
+    }
+
+    let tiers = tiers
+        .into_iter()
+        .map(|(mut tier, indices)| {
+            let mut order: Vec<usize> = (0..indices.len()).collect();
+            order.sort_by_key(|&i| indices[i]);
+            tier.intervals = order.iter().filter_map(|&i| tier.intervals.get(i).cloned()).collect();
+            tier.points = order.iter().filter_map(|&i| tier.points.get(i).cloned()).collect();
+            tier
+        })
+        .collect();
+    Ok(TextGrid::new(xmin, xmax)?.with_tiers(tiers))
+}
+
+/// Splits CSV text into records of unescaped fields, honoring quoted fields that span lines.
+fn parse_records(csv: &str) -> Result<Vec<Vec<String>>, TextGridError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+        } else {
+            match c {
+                '"' => in_quotes = true,
+                ',' => record.push(std::mem::take(&mut field)),
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                _ => field.push(c),
+            }
+        }
+    }
+    if in_quotes {
+        return Err(TextGridError::Format(format!("Row {}: unterminated quoted field", records.len() + 1)));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Quotes a CSV field if it contains a comma, quote, or line break, doubling embedded quotes.
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
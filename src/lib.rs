@@ -11,24 +11,19 @@
 //! - **Validation**: Ensure data integrity with bounds and overlap checks.
 //!
 //! ## Usage
-//! ```rust
+//! ```rust,no_run
 //! use textgrid::{TextGrid, Tier, TierType, Interval};
 //!
 //! fn main() -> Result<(), textgrid::TextGridError> {
 //!     // Create a TextGrid
 //!     let mut tg = TextGrid::new(0.0, 10.0)?;
-//!     let tier = Tier {
-//!         name: "words".to_string(),
-//!         tier_type: TierType::IntervalTier,
-//!         xmin: 0.0,
-//!         xmax: 10.0,
-//!         intervals: vec![Interval {
+//!     let tier = Tier::new("words", TierType::IntervalTier, 0.0, 10.0)?
+//!         .with_intervals(vec![Interval {
 //!             xmin: 1.0,
 //!             xmax: 2.0,
 //!             text: "hello".to_string(),
-//!         }],
-//!         points: vec![],
-//!     };
+//!             score: None,
+//!         }]);
 //!     tg.add_tier(tier)?;
 //!
 //!     // Save to file
@@ -36,7 +31,7 @@
 //!
 //!     // Load from file
 //!     let loaded = TextGrid::from_file("example.TextGrid")?;
-//!     assert_eq!(loaded.tiers[0].intervals[0].text, "hello");
+//!     assert_eq!(loaded.tiers()[0].intervals()[0].text, "hello");
 //!     Ok(())
 //! }
 //! ```
@@ -46,13 +41,28 @@ mod types;
 mod writer;
 mod validator;
 mod binary;
+mod csv;
+mod webvtt;
+mod audacity;
+#[cfg(feature = "bincode")]
+mod cache;
 
-pub use types::{Interval, Point, TextGrid, TextGridError, Tier, TierType};
+pub use types::{BlankHandling, CollapseTarget, ContiguityStrategy, EmptyPolicy, Interval, Point, RateDenominator, TextGrid, TextGridBuilder, TextGridError, Tier, TierReport, TierType, TiersMut, TimeSnapshot};
+pub use parser::parse_textgrid;
+pub use writer::{write_textgrid, write_textgrid_to, write_textgrid_with_options, TextWriteOptions};
+pub use validator::{validate_textgrid, validate_textgrid_with, ValidationOptions};
+pub use binary::{read_binary, write_binary, BinaryWriteOptions};
+use std::io::BufRead;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 impl TextGrid {
     /// Loads a TextGrid from a file (text or binary format).
     ///
+    /// The loaded TextGrid is validated like every file this crate reads or writes: duplicate tier
+    /// names are accepted, as in Praat, even though [`validate_textgrid`] rejects them by default.
+    /// Use [`TextGrid::from_file_with`] to choose the checks.
+    ///
     /// # Arguments
     /// * `path` - Path to the `.TextGrid` file, implementing `AsRef<Path>`.
     ///
@@ -64,29 +74,261 @@ impl TextGrid {
     /// - `TextGridError::IO` if the file cannot be opened or read.
     ///
     /// # Examples
-    /// ```rust
+    /// ```rust,no_run
+    /// use textgrid::TextGrid;
+    ///
     /// let tg = TextGrid::from_file("example.TextGrid").unwrap();
-    /// assert_eq!(tg.tiers.len(), 1); // Assuming one tier in the file
+    /// assert_eq!(tg.tiers().len(), 1); // Assuming one tier in the file
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TextGridError> {
+        Self::from_file_with(path, &io_validation_options())
+    }
+
+    /// Loads a TextGrid from a file (text or binary format), validating it with explicit options.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.TextGrid` file, implementing `AsRef<Path>`.
+    /// * `options` - Validation options applied to the loaded TextGrid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the loaded `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the file extension is unsupported or missing, if the file is malformed,
+    ///   or if it fails validation under `options`.
+    /// - `TextGridError::IO` if the file cannot be opened or read.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use textgrid::{TextGrid, ValidationOptions};
+    ///
+    /// let options = ValidationOptions { require_gapless: true, ..ValidationOptions::default() };
+    /// let tg = TextGrid::from_file_with("example.TextGrid", &options).unwrap();
+    /// ```
+    pub fn from_file_with<P: AsRef<Path>>(path: P, options: &ValidationOptions) -> Result<Self, TextGridError> {
         let path_ref = path.as_ref();
         if let Some(ext) = path_ref.extension() {
-            match ext.to_str().unwrap_or("").to_lowercase().as_str() {
-                "textgrid" => {
-                    let textgrid = parser::parse_textgrid(path)?;
-                    validator::validate_textgrid(&textgrid)?;
-                    Ok(textgrid)
+            let textgrid = match ext.to_str().unwrap_or("").to_lowercase().as_str() {
+                "textgrid" => parser::parse_textgrid(path)?,
+                "textgridbin" => binary::read_binary(path)?,
+                _ => return Err(TextGridError::Format("Unsupported file extension".into())),
+            };
+            validator::validate_textgrid_with(&textgrid, options)?;
+            Ok(textgrid)
+        } else {
+            Err(TextGridError::Format("No file extension".into()))
+        }
+    }
+
+    /// Loads every `.TextGrid` file in a directory, keyed by file stem.
+    ///
+    /// Only files with a `.TextGrid` extension (case-insensitive) are read; other entries and
+    /// subdirectories are skipped. A file that fails to load is left out of the map rather than
+    /// aborting the whole load, so one corrupt file does not hide the rest of a corpus. Only if every
+    /// `.TextGrid` file fails is an error returned.
+    ///
+    /// # Arguments
+    /// * `dir` - Path to the directory, implementing `AsRef<Path>`.
+    ///
+    /// # Returns
+    /// Returns a map from file stem to `TextGrid`, which is empty if the directory has no `.TextGrid` files.
+    ///
+    /// # Errors
+    /// - `TextGridError::IO` if the directory cannot be read.
+    /// - `TextGridError::Format` naming the first failing file if every `.TextGrid` file fails to load.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<std::collections::BTreeMap<String, TextGrid>, TextGridError> {
+        Self::from_dir_with(dir, &io_validation_options())
+    }
+
+    /// Loads every `.TextGrid` file in a directory, keyed by file stem, validating each with explicit options.
+    ///
+    /// Behaves like [`TextGrid::from_dir`], except that each file is loaded with [`TextGrid::from_file_with`].
+    ///
+    /// # Arguments
+    /// * `dir` - Path to the directory, implementing `AsRef<Path>`.
+    /// * `options` - Validation options applied to each loaded TextGrid.
+    ///
+    /// # Returns
+    /// Returns a map from file stem to `TextGrid`, which is empty if the directory has no `.TextGrid` files.
+    ///
+    /// # Errors
+    /// - `TextGridError::IO` if the directory cannot be read.
+    /// - `TextGridError::Format` naming the first failing file if every `.TextGrid` file fails to load.
+    pub fn from_dir_with<P: AsRef<Path>>(
+        dir: P,
+        options: &ValidationOptions,
+    ) -> Result<std::collections::BTreeMap<String, TextGrid>, TextGridError> {
+        let mut textgrids = std::collections::BTreeMap::new();
+        let mut first_error = None;
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_textgrid = path.is_file()
+                && path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("textgrid"));
+            let Some(stem) = path.file_stem().filter(|_| is_textgrid) else {
+                continue;
+            };
+            match TextGrid::from_file_with(&path, options) {
+                Ok(textgrid) => {
+                    textgrids.insert(stem.to_string_lossy().into_owned(), textgrid);
                 }
-                "textgridbin" => {
-                    let textgrid = binary::read_binary(path)?;
-                    validator::validate_textgrid(&textgrid)?;
-                    Ok(textgrid)
+                Err(error) => {
+                    first_error.get_or_insert_with(|| format!("Failed to load '{}': {}", path.display(), error));
                 }
-                _ => Err(TextGridError::Format("Unsupported file extension".into())),
             }
-        } else {
-            Err(TextGridError::Format("No file extension".into()))
         }
+        match first_error {
+            Some(message) if textgrids.is_empty() => Err(TextGridError::Format(message)),
+            _ => Ok(textgrids),
+        }
+    }
+
+    /// Loads a TextGrid from a file, applying recoverable fixes and reporting each one.
+    ///
+    /// The format is detected from the content rather than the extension. Invalid UTF-8 is
+    /// replaced, TextGrid bounds are widened to cover all tiers, unsorted intervals and points
+    /// are sorted, intervals are clipped to their tier, points are clamped into their tier, and a
+    /// tier reusing an earlier tier's name is renamed (`words` becomes `words (2)`).
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.TextGrid` file, implementing `AsRef<Path>`.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the repaired `TextGrid` and a human-readable warning for
+    /// each fix applied, or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the file is malformed or still invalid after the fixes (e.g. overlapping intervals).
+    /// - `TextGridError::IO` if the file cannot be opened or read.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use textgrid::TextGrid;
+    ///
+    /// let (tg, warnings) = TextGrid::from_file_lenient("example.TextGrid").unwrap();
+    /// for warning in &warnings {
+    ///     eprintln!("example.TextGrid: {}", warning);
+    /// }
+    /// ```
+    pub fn from_file_lenient<P: AsRef<Path>>(path: P) -> Result<(Self, Vec<String>), TextGridError> {
+        let bytes = std::fs::read(path)?;
+        let mut warnings = Vec::new();
+        let mut textgrid = if bytes.starts_with(b"ooBinaryFile") {
+            binary::parse_binary(&bytes)?
+        } else {
+            let content = String::from_utf8_lossy(&bytes);
+            if let std::borrow::Cow::Owned(_) = content {
+                warnings.push("Invalid UTF-8 sequences replaced with U+FFFD".to_string());
+            }
+            parser::parse_textgrid_str(&content)?
+        };
+        warnings.extend(validator::repair_textgrid(&mut textgrid));
+        validate_for_io(&textgrid)?;
+        Ok((textgrid, warnings))
+    }
+
+    /// Parses a TextGrid from raw bytes, detecting the text or binary format.
+    ///
+    /// Input starting with the `ooBinaryFile` magic is read as Praat binary; anything else is
+    /// decoded as UTF-8 text. This function never panics, whatever the input, which makes it
+    /// suitable as a fuzzing entrypoint.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw contents of a `.TextGrid` file.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the parsed and validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the data is not valid UTF-8 text, is malformed, or fails validation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use textgrid::TextGrid;
+    ///
+    /// assert!(TextGrid::parse_bytes(b"ooBinaryFile\x08\x00Text").is_err());
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<Self, TextGridError> {
+        Self::parse_bytes_with(bytes, &io_validation_options())
+    }
+
+    /// Parses a TextGrid from raw bytes, detecting the text or binary format, and validates it with
+    /// explicit options.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw contents of a `.TextGrid` file.
+    /// * `options` - Validation options applied to the parsed TextGrid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the parsed and validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the data is not valid UTF-8 text, is malformed, or fails validation under `options`.
+    pub fn parse_bytes_with(bytes: &[u8], options: &ValidationOptions) -> Result<Self, TextGridError> {
+        let textgrid = if bytes.starts_with(b"ooBinaryFile") {
+            binary::parse_binary(bytes)?
+        } else {
+            parser::parse_textgrid_str(&String::from_utf8(bytes.to_vec())?)?
+        };
+        validator::validate_textgrid_with(&textgrid, options)?;
+        Ok(textgrid)
+    }
+
+    /// Parses a Praat binary TextGrid from raw bytes, also returning any bytes after the declared tiers.
+    ///
+    /// [`TextGrid::parse_bytes`] ignores such trailing bytes; use this to check that none remain, or to
+    /// read data other tools appended.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw contents of a binary `.TextGrid` file.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the parsed and validated `TextGrid` and the trailing bytes (empty if
+    /// there are none), or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the data is not a Praat binary TextGrid, is malformed, or fails validation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use textgrid::TextGrid;
+    ///
+    /// assert!(TextGrid::parse_binary_with_trailing(b"ooTextFile").is_err());
+    /// ```
+    pub fn parse_binary_with_trailing(bytes: &[u8]) -> Result<(Self, &[u8]), TextGridError> {
+        let (textgrid, trailing) = binary::parse_binary_with_trailing(bytes)?;
+        validate_for_io(&textgrid)?;
+        Ok((textgrid, trailing))
+    }
+
+    /// Reads a text-format TextGrid from a reader, transforming every label as it is read.
+    ///
+    /// `f` is applied to each interval text and point mark while the TextGrid is built, which avoids a
+    /// second pass for normalization such as lowercasing. Tier names are not transformed.
+    ///
+    /// # Arguments
+    /// * `reader` - Buffered reader over the text of a `.TextGrid` file (long or short format).
+    /// * `f` - Function mapping each label read to the label stored.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the parsed and validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::IO` if reading fails.
+    /// - `TextGridError::Format` if the content is malformed or fails validation.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use textgrid::TextGrid;
+    ///
+    /// let reader = BufReader::new(File::open("example.TextGrid").unwrap());
+    /// let tg = TextGrid::from_reader_with_label_fn(reader, |label| label.to_lowercase()).unwrap();
+    /// ```
+    pub fn from_reader_with_label_fn<R: BufRead, F: Fn(String) -> String>(reader: R, f: F) -> Result<Self, TextGridError> {
+        let textgrid = parser::parse_reader_with_label_fn(reader, &f)?;
+        validate_for_io(&textgrid)?;
+        Ok(textgrid)
     }
 
     /// Writes a TextGrid to a file in text format.
@@ -99,19 +341,150 @@ impl TextGrid {
     /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
     ///
     /// # Errors
-    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals).
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
     /// - `TextGridError::IO` if the file cannot be created or written to.
     ///
     /// # Examples
-    /// ```rust
+    /// ```rust,no_run
+    /// use textgrid::TextGrid;
+    ///
     /// let tg = TextGrid::new(0.0, 5.0).unwrap(); // Assume tiers are added
     /// tg.to_file("test.TextGrid", false).unwrap();
     /// ```
     pub fn to_file<P: AsRef<Path>>(&self, path: P, short_format: bool) -> Result<(), TextGridError> {
-        validator::validate_textgrid(self)?;
+        validate_for_io(self)?;
         writer::write_textgrid(self, path, short_format)
     }
 
+    /// Writes a TextGrid in text format to any writer, such as a socket or an in-memory buffer.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination to write to.
+    /// * `short_format` - If `true`, uses the short text format; otherwise, uses the long format.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
+    /// - `TextGridError::IO` if writing fails.
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W, short_format: bool) -> Result<(), TextGridError> {
+        validate_for_io(self)?;
+        writer::write_textgrid_to(self, writer, short_format, &TextWriteOptions::default())
+    }
+
+    /// Serializes a TextGrid to a string in text format.
+    ///
+    /// # Arguments
+    /// * `short_format` - If `true`, uses the short text format; otherwise, uses the long format.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the text of the `.TextGrid` file or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
+    pub fn to_text_string(&self, short_format: bool) -> Result<String, TextGridError> {
+        let mut buffer = Vec::new();
+        self.write_to(&mut buffer, short_format)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Writes a TextGrid to a file in text format without ever leaving a partially written file.
+    ///
+    /// The data is written to a temporary file next to `path`, flushed to disk, and then renamed over
+    /// `path`, so after a crash the target holds either its previous contents or the complete new TextGrid.
+    /// On Unix the containing directory is synced as well, so the rename itself is durable.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the output file, implementing `AsRef<Path>`.
+    /// * `short_format` - If `true`, uses the short text format; otherwise, uses the long format.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
+    /// - `TextGridError::IO` if the temporary file cannot be written or renamed, or the directory cannot be synced.
+    pub fn to_file_atomic<P: AsRef<Path>>(&self, path: P, short_format: bool) -> Result<(), TextGridError> {
+        validate_for_io(self)?;
+        write_atomic(path.as_ref(), |temp| writer::write_textgrid(self, temp, short_format))
+    }
+
+    /// Writes a TextGrid to a file in binary format without ever leaving a partially written file.
+    ///
+    /// See [`TextGrid::to_file_atomic`] for how the file is replaced.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the output file, implementing `AsRef<Path>`.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
+    /// - `TextGridError::IO` if the temporary file cannot be written or renamed, or the directory cannot be synced.
+    pub fn to_binary_file_atomic<P: AsRef<Path>>(&self, path: P) -> Result<(), TextGridError> {
+        validate_for_io(self)?;
+        write_atomic(path.as_ref(), |temp| binary::write_binary(self, temp))
+    }
+
+    /// Writes a TextGrid to a file in text format with explicit write options.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the output file, implementing `AsRef<Path>`.
+    /// * `short_format` - If `true`, uses the short text format; otherwise, uses the long format.
+    /// * `options` - Text write options.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
+    /// - `TextGridError::IO` if the file cannot be created or written to.
+    pub fn to_file_with_options<P: AsRef<Path>>(&self, path: P, short_format: bool, options: &TextWriteOptions) -> Result<(), TextGridError> {
+        validate_for_io(self)?;
+        writer::write_textgrid_with_options(self, path, short_format, options)
+    }
+
+    /// Writes only the named tiers to a file in text format, leaving the TextGrid unchanged.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the output file, implementing `AsRef<Path>`.
+    /// * `tier_names` - Names of the tiers to write, in the order they should appear.
+    /// * `short_format` - If `true`, uses the short text format; otherwise, uses the long format.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if a named tier is missing or the selected data is invalid.
+    /// - `TextGridError::IO` if the file cannot be created or written to.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use textgrid::TextGrid;
+    ///
+    /// let tg = TextGrid::from_file("example.TextGrid").unwrap();
+    /// tg.write_tiers_to_file("words.TextGrid", &["words"], false).unwrap();
+    /// ```
+    pub fn write_tiers_to_file<P: AsRef<Path>>(&self, path: P, tier_names: &[&str], short_format: bool) -> Result<(), TextGridError> {
+        let tiers = tier_names
+            .iter()
+            .map(|name| {
+                self.get_tier(name)
+                    .cloned()
+                    .ok_or_else(|| TextGridError::Format(format!("Tier '{}' not found", name)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        TextGrid::new(self.xmin, self.xmax)?.with_tiers(tiers).to_file(path, short_format)
+    }
+
     /// Writes a TextGrid to a file in binary format.
     ///
     /// # Arguments
@@ -121,79 +494,332 @@ impl TextGrid {
     /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
     ///
     /// # Errors
-    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals).
+    /// - `TextGridError::Format` if the TextGrid data is invalid (e.g., overlapping intervals). Duplicate tier
+    ///   names are allowed, as in Praat.
     /// - `TextGridError::IO` if the file cannot be created or written to.
     ///
     /// # Examples
-    /// ```rust
+    /// ```rust,no_run
+    /// use textgrid::TextGrid;
+    ///
     /// let tg = TextGrid::new(0.0, 5.0).unwrap(); // Assume tiers are added
     /// tg.to_binary_file("test.textgridbin").unwrap();
     /// ```
     pub fn to_binary_file<P: AsRef<Path>>(&self, path: P) -> Result<(), TextGridError> {
-        validator::validate_textgrid(self)?;
+        validate_for_io(self)?;
         binary::write_binary(self, path)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_undo_redo() {
-        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
-        let tier = Tier {
-            name: "test".to_string(),
-            tier_type: TierType::IntervalTier,
-            xmin: 0.0,
-            xmax: 5.0,
-            intervals: vec![Interval { xmin: 0.0, xmax: 5.0, text: "test".to_string() }],
-            points: vec![],
-        };
-        textgrid.add_tier(tier.clone()).unwrap();
-        assert_eq!(textgrid.tiers.len(), 1);
-        textgrid.undo().unwrap();
-        assert_eq!(textgrid.tiers.len(), 0);
-        textgrid.redo().unwrap();
-        assert_eq!(textgrid.tiers.len(), 1);
+    /// Exports the whole TextGrid as a long-format CSV table.
+    ///
+    /// Columns are `tier,type,index,xmin,xmax,text,score`, with one row per interval or point. Point rows
+    /// use `xmin == xmax == time` and carry the mark in `text`. `score` is empty for points and unscored
+    /// intervals. Fields are CSV-escaped.
+    ///
+    /// # Returns
+    /// Returns the CSV text including a header row.
+    pub fn to_long_csv(&self) -> String {
+        csv::write_long_csv(self)
+    }
 
-        textgrid.tier_add_interval("test", Interval { xmin: 2.0, xmax: 3.0, text: "new".to_string() }).unwrap();
-        assert_eq!(textgrid.get_tier("test").unwrap().intervals.len(), 2);
-        textgrid.undo().unwrap();
-        assert_eq!(textgrid.get_tier("test").unwrap().intervals.len(), 1);
+    /// Exports one IntervalTier as WebVTT subtitles.
+    ///
+    /// Each non-empty interval becomes a cue, in time order, with times written as `HH:MM:SS.mmm`.
+    /// Intervals shorter than the millisecond rounding are skipped, since a cue must end after it starts.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the IntervalTier to export.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the WebVTT text or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not found, is not an IntervalTier, or has a negative time.
+    pub fn to_webvtt(&self, tier_name: &str) -> Result<String, TextGridError> {
+        let tier = self.get_tier(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        webvtt::write_webvtt(tier)
     }
 
-    #[test]
-    fn test_advanced_merge() {
-        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
-        textgrid.add_tier(Tier {
-            name: "t1".to_string(),
-            tier_type: TierType::IntervalTier,
-            xmin: 0.0,
-            xmax: 5.0,
-            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "a".to_string() }],
-            points: vec![],
-        }).unwrap();
-        textgrid.add_tier(Tier {
-            name: "t2".to_string(),
-            tier_type: TierType::IntervalTier,
-            xmin: 0.0,
-            xmax: 5.0,
-            intervals: vec![Interval { xmin: 1.0, xmax: 3.0, text: "b".to_string() }],
-            points: vec![],
-        }).unwrap();
-        textgrid.merge_tiers_with_strategy("t1", "t2", "merged".to_string(), |a, b| {
-            Some(Interval {
-                xmin: a.xmin,
-                xmax: a.xmax.max(b.xmax),
-                text: format!("{}-{}", a.text, b.text),
-            })
-        }).unwrap();
-        let merged = textgrid.get_tier("merged").unwrap();
-        assert_eq!(merged.intervals[0].text, "a-b");
+    /// Imports a TextGrid from the long-format CSV produced by [`TextGrid::to_long_csv`].
+    ///
+    /// Tiers are grouped by the `tier` column in order of first appearance, with their type taken from
+    /// the `type` column. All tiers span `[xmin, xmax]`. Tables without the `score` column, as written
+    /// by older versions, are also accepted.
+    ///
+    /// # Arguments
+    /// * `csv` - The CSV text, including its header row.
+    /// * `xmin` - Start time of the TextGrid.
+    /// * `xmax` - End time of the TextGrid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if a row is malformed (the message names the row) or the result fails validation.
+    pub fn from_long_csv(csv: &str, xmin: f64, xmax: f64) -> Result<Self, TextGridError> {
+        let textgrid = csv::read_long_csv(csv, xmin, xmax)?;
+        validate_for_io(&textgrid)?;
+        Ok(textgrid)
     }
 
-    #[test]
+    /// Appends a tier to a TextGrid file on disk, keeping the file's format.
+    ///
+    /// The file is loaded, the tier is added (checking it fits within the grid bounds), and the
+    /// result is written back in the same format it was read in: binary for `.textgridbin`,
+    /// otherwise the long or short text format detected from the file. The file is replaced
+    /// atomically, as in [`TextGrid::to_file_atomic`], so a failed write leaves it intact.
+    ///
+    /// # Arguments
+    /// * `path` - Path to an existing TextGrid file, implementing `AsRef<Path>`.
+    /// * `tier` - The tier to append.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the file is malformed or the tier is out of the grid's bounds.
+    /// - `TextGridError::IO` if the file cannot be read or written.
+    pub fn append_tier_to_file<P: AsRef<Path>>(path: P, tier: Tier) -> Result<(), TextGridError> {
+        let path = path.as_ref();
+        // Load with the same checks the writers apply, so any file that loads can be written back.
+        let mut textgrid = TextGrid::from_file_with(path, &io_validation_options())?;
+        textgrid.add_tier(tier)?;
+        let is_binary = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("textgridbin"));
+        if is_binary {
+            textgrid.to_binary_file_atomic(path)
+        } else {
+            let short_format = parser::detect_short_format(path)?;
+            textgrid.to_file_atomic(path, short_format)
+        }
+    }
+
+    /// Encodes the TextGrid into compact cache bytes (requires the `bincode` feature).
+    ///
+    /// The encoding preserves bounds and tier order but not history. It is **not** Praat-compatible;
+    /// use [`TextGrid::to_binary_file`] for interchange.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the encoded bytes or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if encoding fails.
+    #[cfg(feature = "bincode")]
+    pub fn to_cache_bytes(&self) -> Result<Vec<u8>, TextGridError> {
+        cache::encode_cache(self)
+    }
+
+    /// Decodes a TextGrid from bytes produced by [`TextGrid::to_cache_bytes`] (requires the `bincode` feature).
+    ///
+    /// # Arguments
+    /// * `bytes` - The cached bytes.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the decoded and validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the bytes are malformed or the decoded data is invalid.
+    #[cfg(feature = "bincode")]
+    pub fn from_cache_bytes(bytes: &[u8]) -> Result<Self, TextGridError> {
+        let textgrid = cache::decode_cache(bytes)?;
+        validate_for_io(&textgrid)?;
+        Ok(textgrid)
+    }
+
+    /// Writes a TextGrid to a file in binary format with explicit write options.
+    ///
+    /// When `options.snap_epsilon` is set, near-equal adjacent interval boundaries are snapped
+    /// together before validation and writing, so the file re-reads as a valid TextGrid.
+    /// The in-memory TextGrid is not modified.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the output file, implementing `AsRef<Path>`.
+    /// * `options` - Binary write options.
+    ///
+    /// # Returns
+    /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the (snapped) TextGrid data is invalid.
+    /// - `TextGridError::IO` if the file cannot be created or written to.
+    pub fn to_binary_file_with_options<P: AsRef<Path>>(&self, path: P, options: &BinaryWriteOptions) -> Result<(), TextGridError> {
+        match options.snap_epsilon {
+            Some(epsilon) => {
+                let mut tiers = self.tiers.clone();
+                for tier in &mut tiers {
+                    tier.snap_boundaries(epsilon);
+                }
+                TextGrid::new(self.xmin, self.xmax)?.with_tiers(tiers).to_binary_file(path)
+            }
+            None => self.to_binary_file(path),
+        }
+    }
+}
+
+impl std::str::FromStr for TextGrid {
+    type Err = TextGridError;
+
+    /// Parses a text-format TextGrid (long or short format) held in memory.
+    ///
+    /// # Arguments
+    /// * `content` - The full text of a `.TextGrid` file.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the parsed and validated `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the content is malformed or fails validation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use textgrid::TextGrid;
+    ///
+    /// let content = "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n0\n1\n0\n";
+    /// let tg: TextGrid = content.parse().unwrap();
+    /// assert_eq!(tg.xmax, 1.0);
+    /// ```
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let textgrid = parser::parse_textgrid_str(content)?;
+        validate_for_io(&textgrid)?;
+        Ok(textgrid)
+    }
+}
+
+/// Validation options used when reading or writing TextGrid data.
+///
+/// These are the defaults, except that duplicate tier names are allowed: Praat writes them, so a
+/// valid Praat file must load, and whatever loads must also write.
+fn io_validation_options() -> ValidationOptions {
+    ValidationOptions { allow_duplicate_tier_names: true, ..ValidationOptions::default() }
+}
+
+/// Validates a TextGrid being read or written with [`io_validation_options`].
+fn validate_for_io(textgrid: &TextGrid) -> Result<(), TextGridError> {
+    validator::validate_textgrid_with(textgrid, &io_validation_options())
+}
+
+/// Counter distinguishing the temporary files of concurrent atomic writes within one process.
+static ATOMIC_WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs `write` against a temporary sibling of `path`, syncs it, and renames it over `path`.
+///
+/// The temporary file name includes the process id and a per-call counter, so concurrent saves of
+/// the same path never share one. The temporary file is removed if writing or syncing fails. On
+/// Unix the parent directory is synced after the rename, so the rename itself survives a crash.
+fn write_atomic<F>(path: &Path, write: F) -> Result<(), TextGridError>
+where
+    F: FnOnce(&Path) -> Result<(), TextGridError>,
+{
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| TextGridError::Format(format!("Not a file path: '{}'", path.display())))?;
+    let temp = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    // Windows cannot flush a read-only handle, so sync through a writable one.
+    let result = write(&temp).and_then(|()| Ok(std::fs::OpenOptions::new().write(true).open(&temp)?.sync_all()?));
+    if let Err(error) = result {
+        let _ = std::fs::remove_file(&temp);
+        return Err(error);
+    }
+    std::fs::rename(&temp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temp);
+    })?;
+    #[cfg(unix)]
+    {
+        let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        std::fs::File::open(parent)?.sync_all()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval_tier(name: &str, xmin: f64, xmax: f64, intervals: &[(f64, f64, &str)]) -> Tier {
+        Tier {
+            name: name.to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin,
+            xmax,
+            intervals: intervals.iter().map(|&(xmin, xmax, text)| Interval { xmin, xmax, text: text.to_string(), score: None }).collect(),
+            points: vec![],
+        }
+    }
+
+    fn point_tier(name: &str, xmin: f64, xmax: f64, points: &[(f64, &str)]) -> Tier {
+        Tier {
+            name: name.to_string(),
+            tier_type: TierType::PointTier,
+            xmin,
+            xmax,
+            intervals: vec![],
+            points: points.iter().map(|&(time, mark)| Point { time, mark: mark.to_string() }).collect(),
+        }
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        let tier = Tier {
+            name: "test".to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin: 0.0,
+            xmax: 5.0,
+            intervals: vec![Interval { xmin: 0.0, xmax: 5.0, text: "test".to_string(), score: None }],
+            points: vec![],
+        };
+        textgrid.add_tier(tier.clone()).unwrap();
+        assert_eq!(textgrid.tiers.len(), 1);
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.tiers.len(), 0);
+        textgrid.redo().unwrap();
+        assert_eq!(textgrid.tiers.len(), 1);
+
+        textgrid.tier_add_interval("test", Interval { xmin: 2.0, xmax: 3.0, text: "new".to_string(), score: None }).unwrap();
+        assert_eq!(textgrid.get_tier("test").unwrap().intervals.len(), 2);
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("test").unwrap().intervals.len(), 1);
+    }
+
+    #[test]
+    fn test_advanced_merge() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(Tier {
+            name: "t1".to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin: 0.0,
+            xmax: 5.0,
+            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "a".to_string(), score: None }],
+            points: vec![],
+        }).unwrap();
+        textgrid.add_tier(Tier {
+            name: "t2".to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin: 0.0,
+            xmax: 5.0,
+            intervals: vec![Interval { xmin: 1.0, xmax: 3.0, text: "b".to_string(), score: None }],
+            points: vec![],
+        }).unwrap();
+        textgrid.merge_tiers_with_strategy("t1", "t2", "merged".to_string(), |a, b| {
+            Some(Interval {
+                xmin: a.xmin,
+                xmax: a.xmax.max(b.xmax),
+                text: format!("{}-{}", a.text, b.text),
+                score: None,
+            })
+        }).unwrap();
+        let merged = textgrid.get_tier("merged").unwrap();
+        assert_eq!(merged.intervals[0].text, "a-b");
+    }
+
+    #[test]
     fn test_query() {
         let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
         textgrid.add_tier(Tier {
@@ -201,7 +827,7 @@ mod tests {
             tier_type: TierType::IntervalTier,
             xmin: 0.0,
             xmax: 5.0,
-            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "hello".to_string() }],
+            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "hello".to_string(), score: None }],
             points: vec![],
         }).unwrap();
         let results = textgrid.query_intervals_by_text("hello");
@@ -217,7 +843,7 @@ mod tests {
             tier_type: TierType::IntervalTier,
             xmin: 0.0,
             xmax: 5.0,
-            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "hello".to_string() }],
+            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "hello".to_string(), score: None }],
             points: vec![],
         }).unwrap();
         textgrid.to_binary_file("test.textgridbin").unwrap();
@@ -225,4 +851,2145 @@ mod tests {
         assert_eq!(loaded.tiers[0].intervals[0].text, "hello");
         std::fs::remove_file("test.textgridbin").unwrap();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_bytes_never_panics() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(Tier {
+            name: "words".to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin: 0.0,
+            xmax: 5.0,
+            intervals: vec![Interval { xmin: 0.0, xmax: 2.0, text: "hello".to_string(), score: None }],
+            points: vec![],
+        }).unwrap();
+        textgrid.add_tier(Tier {
+            name: "tones".to_string(),
+            tier_type: TierType::PointTier,
+            xmin: 0.0,
+            xmax: 5.0,
+            intervals: vec![],
+            points: vec![Point { time: 1.5, mark: "H*".to_string() }],
+        }).unwrap();
+
+        let dir = std::env::temp_dir();
+        let long_path = dir.join("textgrid_parse_bytes_long.TextGrid");
+        let short_path = dir.join("textgrid_parse_bytes_short.TextGrid");
+        let binary_path = dir.join("textgrid_parse_bytes.textgridbin");
+        textgrid.to_file(&long_path, false).unwrap();
+        textgrid.to_file(&short_path, true).unwrap();
+        textgrid.to_binary_file(&binary_path).unwrap();
+        let sources: Vec<Vec<u8>> = [&long_path, &short_path, &binary_path]
+            .iter()
+            .map(|path| {
+                let bytes = std::fs::read(path).unwrap();
+                std::fs::remove_file(path).unwrap();
+                bytes
+            })
+            .collect();
+
+        for source in &sources {
+            let parsed = TextGrid::parse_bytes(source).unwrap();
+            assert_eq!(parsed.tiers.len(), 2);
+            assert_eq!(parsed.tiers[1].points[0].mark, "H*");
+            for len in 0..source.len() {
+                let _ = TextGrid::parse_bytes(&source[..len]);
+            }
+        }
+
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..2000 {
+            let mut bytes = sources[(next() % 3) as usize].clone();
+            for _ in 0..1 + next() % 4 {
+                let index = (next() % bytes.len() as u64) as usize;
+                bytes[index] = next() as u8;
+            }
+            let _ = TextGrid::parse_bytes(&bytes);
+
+            let noise: Vec<u8> = (0..next() % 64).map(|_| next() as u8).collect();
+            let _ = TextGrid::parse_bytes(&noise);
+            let _ = TextGrid::parse_bytes(&[b"ooBinaryFile".as_slice(), &noise].concat());
+        }
+    }
+
+    #[test]
+    fn test_merge_grid_tier_order() {
+        let mut first = TextGrid::new(0.0, 5.0).unwrap();
+        first.add_tier(interval_tier("words", 0.0, 5.0, &[])).unwrap();
+        first.add_tier(interval_tier("phones", 0.0, 5.0, &[])).unwrap();
+        let mut second = TextGrid::new(0.0, 8.0).unwrap();
+        second.add_tier(point_tier("tones", 0.0, 8.0, &[])).unwrap();
+        second.add_tier(interval_tier("breaks", 0.0, 8.0, &[])).unwrap();
+
+        let merged = first.merge_grid(&second).unwrap();
+        let names: Vec<&str> = merged.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words", "phones", "tones", "breaks"]);
+        assert_eq!((merged.xmin, merged.xmax), (0.0, 8.0));
+
+        let sorted = first.merge_grid_sorted(&second).unwrap();
+        let names: Vec<&str> = sorted.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["breaks", "phones", "tones", "words"]);
+    }
+
+    #[test]
+    fn test_interval_adjacency_and_overlap() {
+        let a = Interval { xmin: 0.0, xmax: 1.0, text: "a".to_string(), score: None };
+        let touching = Interval { xmin: 1.0, xmax: 2.0, text: "b".to_string(), score: None };
+        let near = Interval { xmin: 1.0 + 1e-9, xmax: 2.0, text: "c".to_string(), score: None };
+        let overlapping = Interval { xmin: 0.5, xmax: 1.5, text: "d".to_string(), score: None };
+        let disjoint = Interval { xmin: 3.0, xmax: 4.0, text: "e".to_string(), score: None };
+
+        assert!(a.is_adjacent_to(&touching, 0.0));
+        assert!(touching.is_adjacent_to(&a, 0.0));
+        assert!(!a.overlaps(&touching));
+
+        assert!(!a.is_adjacent_to(&near, 0.0));
+        assert!(a.is_adjacent_to(&near, 1e-6));
+        assert!(!a.overlaps(&near));
+
+        assert!(a.overlaps(&overlapping));
+        assert!(overlapping.overlaps(&a));
+        assert!(!a.is_adjacent_to(&overlapping, 1e-6));
+
+        assert!(!a.overlaps(&disjoint));
+        assert!(!a.is_adjacent_to(&disjoint, 1e-6));
+    }
+
+    #[test]
+    fn test_apply_label_map() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "SIL"), (1.0, 2.0, "AX"), (2.0, 3.0, "T"), (3.0, 4.0, "SIL")])).unwrap();
+        let map = std::collections::HashMap::from([
+            ("SIL".to_string(), "".to_string()),
+            ("AX".to_string(), "ə".to_string()),
+        ]);
+
+        let changed = textgrid.tier_apply_label_map("phones", &map).unwrap();
+        assert_eq!(changed, 3);
+        let texts: Vec<&str> = textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["", "ə", "T", ""]);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("phones").unwrap().intervals[1].text, "AX");
+        textgrid.redo().unwrap();
+        assert_eq!(textgrid.get_tier("phones").unwrap().intervals[1].text, "ə");
+    }
+
+    #[test]
+    fn test_tier_validate() {
+        let mut tier = interval_tier("words", 0.0, 5.0, &[(0.0, 2.0, "a"), (2.0, 3.0, "b")]);
+        assert!(tier.validate().is_ok());
+
+        tier.intervals[1].xmin = 1.5;
+        assert!(matches!(tier.validate(), Err(TextGridError::Format(_))));
+
+        let mut points = point_tier("tones", 0.0, 5.0, &[(1.0, "H"), (2.0, "L")]);
+        assert!(points.validate().is_ok());
+        points.points.swap(0, 1);
+        assert!(points.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_without_tiers_flag() {
+        let content = "File type = \"ooTextFile\"
+Object class = \"TextGrid\"
+xmin = 0
+xmax = 2
+size = 1
+item []:
+    item [1]:
+        class = \"IntervalTier\"
+        name = \"words\"
+        xmin = 0
+        xmax = 2
+        intervals: size = 1
+        intervals [1]:
+            xmin = 0
+            xmax = 2
+            text = \"legacy\"
+";
+        let textgrid = TextGrid::parse_bytes(content.as_bytes()).unwrap();
+        assert_eq!(textgrid.tiers[0].intervals[0].text, "legacy");
+
+        let broken = content.replace("size = 1\nitem", "tiers? maybe\nitem");
+        assert!(TextGrid::parse_bytes(broken.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_speech_extent() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 1.0, ""), (1.0, 2.0, "a"), (2.0, 3.5, "b"), (3.5, 5.0, "")])).unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().speech_extent(), Some((1.0, 3.5)));
+
+        textgrid.add_tier(interval_tier("silent", 0.0, 5.0, &[(0.0, 5.0, " ")])).unwrap();
+        assert_eq!(textgrid.get_tier("silent").unwrap().speech_extent(), None);
+
+        textgrid.add_tier(interval_tier("other", 0.0, 5.0, &[(0.5, 1.5, "x")])).unwrap();
+        assert_eq!(textgrid.speech_extent(), Some((0.5, 3.5)));
+    }
+
+    #[test]
+    fn test_trim_to_speech() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 1.0, ""), (1.0, 2.0, "a"), (2.0, 3.5, "b"), (3.5, 5.0, "")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 5.0, &[(0.5, "L"), (1.5, "H")])).unwrap();
+
+        textgrid.trim_to_speech().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (1.0, 3.5));
+        let words = textgrid.get_tier("words").unwrap();
+        assert_eq!(words.intervals.len(), 2);
+        assert_eq!((words.xmin, words.xmax), (1.0, 3.5));
+        assert_eq!(textgrid.get_tier("tones").unwrap().points.len(), 1);
+        assert!(validate_textgrid(&textgrid).is_ok());
+
+        textgrid.undo().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (0.0, 5.0));
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals.len(), 4);
+        textgrid.redo().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (1.0, 3.5));
+    }
+
+    #[test]
+    fn test_tiers_mut_guard() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 5.0, &[(1.0, "H")])).unwrap();
+
+        {
+            let mut tiers = textgrid.tiers_mut();
+            tiers[0].intervals.insert(0, Interval { xmin: 3.0, xmax: 4.0, text: "c".to_string(), score: None });
+            tiers[1].points.push(Point { time: 0.5, mark: "L".to_string() });
+        }
+        let texts: Vec<&str> = textgrid.tiers()[0].intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["a", "b", "c"]);
+        assert_eq!(textgrid.tiers()[1].points[0].mark, "L");
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.tiers()[0].intervals.len(), 2);
+        assert_eq!(textgrid.tiers()[1].points.len(), 1);
+
+        // Reading through the guard records nothing, so the redo survives.
+        assert_eq!(textgrid.tiers_mut().len(), 2);
+        textgrid.redo().unwrap();
+        assert_eq!(textgrid.tiers()[0].intervals.len(), 3);
+        textgrid.undo().unwrap();
+
+        let mut tiers = textgrid.tiers_mut();
+        tiers[0].intervals[1].xmin = 0.5;
+        assert!(tiers.finish().is_err());
+    }
+
+    #[test]
+    fn test_parse_mismatched_tier_class() {
+        let content = "File type = \"ooTextFile\"
+Object class = \"TextGrid\"
+xmin = 0
+xmax = 2
+tiers? <exists>
+size = 1
+item []:
+    item [1]:
+        class = \"IntervalTier\"
+        name = \"tones\"
+        xmin = 0
+        xmax = 2
+        points: size = 1
+        points [1]:
+            time = 1
+            mark = \"H\"
+";
+        match TextGrid::parse_bytes(content.as_bytes()) {
+            Err(TextGridError::Format(message)) => {
+                assert!(message.contains("'tones'"));
+                assert!(message.contains("IntervalTier"));
+            }
+            other => panic!("expected a format error, got {:?}", other),
+        }
+
+        let points_as_intervals = content.replace("points: size = 1\n        points [1]:", "intervals: size = 1\n        intervals [1]:");
+        assert!(TextGrid::parse_bytes(points_as_intervals.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_immutable_updaters() {
+        let interval = Interval { xmin: 1.0, xmax: 2.0, text: "a".to_string(), score: None };
+        let relabeled = interval.with_text("b");
+        assert_eq!(interval.text, "a");
+        assert_eq!((relabeled.xmin, relabeled.xmax, relabeled.text.as_str()), (1.0, 2.0, "b"));
+
+        let moved = interval.with_bounds(3.0, 4.0);
+        assert_eq!((interval.xmin, interval.xmax), (1.0, 2.0));
+        assert_eq!((moved.xmin, moved.xmax, moved.text.as_str()), (3.0, 4.0, "a"));
+
+        let point = Point { time: 1.5, mark: "H".to_string() };
+        let remarked = point.with_mark(String::from("L"));
+        assert_eq!(point.mark, "H");
+        assert_eq!((remarked.time, remarked.mark.as_str()), (1.5, "L"));
+    }
+
+    #[test]
+    fn test_sample_conversion() {
+        let interval = Interval { xmin: 1.0, xmax: 2.0, text: "a".to_string(), score: None };
+        assert_eq!(interval.to_samples(16000.0).unwrap(), (16000, 32000));
+        assert!(interval.to_samples(0.0).is_err());
+        assert!(interval.to_samples(-16000.0).is_err());
+
+        let point = Point { time: 0.5, mark: "H".to_string() };
+        assert_eq!(point.to_sample(16000.0).unwrap(), 8000);
+
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.5, "b")])).unwrap();
+        let segments = textgrid.tier_to_sample_segments("words", 100.0).unwrap();
+        assert_eq!(segments, vec![(0, 100, "a".to_string()), (100, 250, "b".to_string())]);
+        assert!(textgrid.tier_to_sample_segments("missing", 100.0).is_err());
+    }
+
+    #[test]
+    fn test_boundary_times() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 3.0, "b")])).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 3.0, &[(0.0, 0.5, "x"), (0.5, 1.0 + 1e-12, "y"), (1.0 + 1e-12, 2.0, "z")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(2.5, "H")])).unwrap();
+
+        assert_eq!(textgrid.boundary_times(), vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_binary_snap_boundaries() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.tiers.push(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0 + 1e-12, "a"), (1.0, 2.0 - 1e-12, "b")]));
+        let path = std::env::temp_dir().join("textgrid_snap_boundaries.textgridbin");
+
+        assert!(textgrid.to_binary_file(&path).is_err());
+        let options = BinaryWriteOptions { snap_epsilon: Some(1e-9) };
+        textgrid.to_binary_file_with_options(&path, &options).unwrap();
+        let loaded = TextGrid::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let words = &loaded.tiers[0];
+        assert_eq!(words.intervals[0].xmax, words.intervals[1].xmin);
+        assert_eq!(words.intervals[1].xmax, 2.0);
+        assert_eq!(textgrid.tiers[0].intervals[1].xmin, 1.0);
+    }
+
+    #[test]
+    fn test_append_tier_to_file() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 2.0, "hello")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_append_tier.TextGrid");
+        textgrid.to_file(&path, true).unwrap();
+
+        TextGrid::append_tier_to_file(&path, point_tier("tones", 0.0, 5.0, &[(1.0, "H")])).unwrap();
+        assert!(TextGrid::append_tier_to_file(&path, interval_tier("late", 0.0, 9.0, &[])).is_err());
+        let short_format = std::fs::read_to_string(&path).unwrap().lines().nth(2) == Some("0");
+        let loaded = TextGrid::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(short_format);
+        let names: Vec<&str> = loaded.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words", "tones"]);
+    }
+
+    #[test]
+    fn test_tiers_with_content_in_range() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 2.0, "hello"), (2.0, 5.0, "")])).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 5.0, &[(0.0, 3.0, ""), (3.0, 4.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 5.0, &[(4.5, "H")])).unwrap();
+
+        assert_eq!(textgrid.tiers_with_content_in_range(1.0, 2.5), ["words"]);
+        assert_eq!(textgrid.tiers_with_content_in_range(2.0, 3.0), Vec::<&str>::new());
+        assert_eq!(textgrid.tiers_with_content_in_range(3.5, 5.0), ["phones", "tones"]);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_cache_bytes_round_trip() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 2.0, "hello"), (2.0, 5.0, "")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 5.0, &[(1.0, "H")])).unwrap();
+        textgrid.add_tier(interval_tier("alpha", 0.0, 5.0, &[])).unwrap();
+
+        let bytes = textgrid.to_cache_bytes().unwrap();
+        let restored = TextGrid::from_cache_bytes(&bytes).unwrap();
+        let names: Vec<&str> = restored.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words", "tones", "alpha"]);
+        assert_eq!(restored.tiers[0].intervals[0].text, "hello");
+        assert_eq!(restored.tiers[1].tier_type, TierType::PointTier);
+        assert!(TextGrid::from_cache_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let edited = interval_tier("words", 0.0, 5.0, &[(0.0, 1.0, "a"), (1.1, 2.0, "b"), (1.9, 3.0, "c")]);
+
+        let mut next = edited.clone();
+        next.make_contiguous(ContiguityStrategy::SnapToNext).unwrap();
+        let bounds: Vec<(f64, f64)> = next.intervals.iter().map(|i| (i.xmin, i.xmax)).collect();
+        assert_eq!(bounds, [(0.0, 1.1), (1.1, 1.9), (1.9, 3.0)]);
+        assert!(next.validate().is_ok());
+
+        let mut prev = edited.clone();
+        prev.make_contiguous(ContiguityStrategy::SnapToPrev).unwrap();
+        let bounds: Vec<(f64, f64)> = prev.intervals.iter().map(|i| (i.xmin, i.xmax)).collect();
+        assert_eq!(bounds, [(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]);
+        assert!(prev.validate().is_ok());
+
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.tiers.push(edited);
+        textgrid.tier_make_contiguous("words", ContiguityStrategy::SnapToPrev).unwrap();
+        assert_eq!(textgrid.tiers[0].intervals[1].xmin, 1.0);
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.tiers[0].intervals[1].xmin, 1.1);
+    }
+
+    #[test]
+    fn test_labels_at_boundary() {
+        let tier = interval_tier("phones", 0.0, 3.0, &[(0.0, 1.0, "k"), (1.0, 2.0, "a"), (2.0, 3.0, "t")]);
+        assert_eq!(tier.labels_at_boundary(1.0, 1e-9), Some(("k".to_string(), "a".to_string())));
+        assert_eq!(tier.labels_at_boundary(2.0 + 1e-12, 1e-9), Some(("a".to_string(), "t".to_string())));
+        assert_eq!(tier.labels_at_boundary(1.5, 1e-9), None);
+        assert_eq!(tier.labels_at_boundary(0.0, 1e-9), None);
+    }
+
+    #[test]
+    fn test_to_long_csv() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.5, "a, b"), (1.5, 3.0, "say \"hi\"")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(0.75, "H*")])).unwrap();
+
+        assert_eq!(
+            textgrid.to_long_csv(),
+            "tier,type,index,xmin,xmax,text,score\n\
+             words,IntervalTier,0,0,1.5,\"a, b\",\n\
+             words,IntervalTier,1,1.5,3,\"say \"\"hi\"\"\",\n\
+             tones,PointTier,0,0.75,0.75,H*,\n"
+        );
+    }
+
+    #[test]
+    fn test_from_long_csv() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.5, "a, b"), (1.5, 3.0, "line\nbreak \"q\"")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(0.75, "H*"), (2.0, "")])).unwrap();
+
+        let imported = TextGrid::from_long_csv(&textgrid.to_long_csv(), 0.0, 3.0).unwrap();
+        assert!(imported.approx_eq(&textgrid, 1e-12));
+
+        let malformed = "tier,type,index,xmin,xmax,text\nwords,IntervalTier,0,0,1,a\nwords,IntervalTier,1,one,2,b\n";
+        match TextGrid::from_long_csv(malformed, 0.0, 3.0) {
+            Err(TextGridError::Format(message)) => assert!(message.starts_with("Row 3")),
+            other => panic!("expected a format error, got {:?}", other),
+        }
+        assert!(TextGrid::from_long_csv("tier,type\n", 0.0, 3.0).is_err());
+    }
+
+    #[test]
+    fn test_to_frames() {
+        let tier = interval_tier("words", 0.0, 1.0, &[(0.0, 0.5, "a"), (0.5, 1.0, "b")]);
+        let frames = tier.to_frames(0.1).unwrap();
+        assert_eq!(frames, ["a", "a", "a", "a", "a", "b", "b", "b", "b", "b"]);
+
+        let gappy = interval_tier("words", 0.0, 1.0, &[(0.0, 0.4, "a")]);
+        assert_eq!(gappy.to_frames(0.25).unwrap(), ["a", "a", "", ""]);
+
+        assert!(tier.to_frames(0.0).is_err());
+        assert!(tier.to_frames(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_empty_counts() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, ""), (1.0, 2.0, "a"), (2.0, 3.0, " ")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(0.5, ""), (1.5, "H")])).unwrap();
+
+        let words = textgrid.get_tier("words").unwrap();
+        assert_eq!(words.empty_interval_count(), 2);
+        assert_eq!(words.nonempty_interval_count(), 1);
+        assert_eq!(words.interval_label_counts(), (2, 1));
+        assert_eq!(textgrid.get_tier("tones").unwrap().empty_point_count(), 1);
+        assert_eq!(textgrid.empty_interval_count(), 2);
+    }
+
+    #[test]
+    fn test_split_with_texts() {
+        let interval = Interval { xmin: 0.0, xmax: 2.0, text: "ab".into(), score: None };
+        let (left, right) = interval.split_with_texts(1.0, "a".into(), "b".into()).unwrap();
+        assert_eq!((left.xmax, left.text.as_str()), (1.0, "a"));
+        assert_eq!((right.xmin, right.text.as_str()), (1.0, "b"));
+
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "ab")])).unwrap();
+        textgrid.tier_split_interval_with_texts("words", 0, 1.0, "a".into(), "b".into()).unwrap();
+        let texts: Vec<_> = textgrid.get_tier("words").unwrap().intervals.iter().map(|i| i.text.clone()).collect();
+        assert_eq!(texts, ["a", "b"]);
+
+        textgrid.undo().unwrap();
+        let words = textgrid.get_tier("words").unwrap();
+        assert_eq!(words.intervals.len(), 1);
+        assert_eq!(words.intervals[0].text, "ab");
+    }
+
+    #[test]
+    fn test_snapshot_and_restore_times() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H")])).unwrap();
+        let snap = textgrid.snapshot_times();
+
+        {
+            let mut tiers = textgrid.tiers_mut();
+            for tier in tiers.iter_mut() {
+                tier.xmax *= 2.0;
+                for interval in &mut tier.intervals {
+                    interval.xmin *= 2.0;
+                    interval.xmax *= 2.0;
+                }
+                for point in &mut tier.points {
+                    point.time *= 2.0;
+                }
+            }
+            tiers[0].intervals[1].text = "c".into();
+        }
+        textgrid.xmax = 4.0;
+
+        textgrid.restore_times(snap.clone()).unwrap();
+        assert_eq!(textgrid.xmax, 2.0);
+        let words = textgrid.get_tier("words").unwrap();
+        assert_eq!((words.xmax, words.intervals[1].xmin, words.intervals[1].xmax), (2.0, 1.0, 2.0));
+        assert_eq!(words.intervals[1].text, "c");
+        assert_eq!(textgrid.get_tier("tones").unwrap().points[0].time, 0.5);
+
+        textgrid.tier_remove_interval("words", 1).unwrap();
+        assert!(textgrid.restore_times(snap).is_err());
+    }
+
+    #[test]
+    fn test_interval_score() {
+        let mut tier = interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, "c")]);
+        assert_eq!(tier.mean_score(), None);
+        tier.intervals[0] = tier.intervals[0].with_score(0.5);
+        tier.intervals[1] = tier.intervals[1].with_score(1.0);
+        assert_eq!(tier.mean_score(), Some(0.75));
+
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(tier).unwrap();
+        let path = std::env::temp_dir().join("textgrid_score_test.TextGrid");
+        textgrid.to_file(&path, false).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!written.contains("score") && !written.contains("0.5"));
+
+        let csv = textgrid.to_long_csv();
+        assert!(csv.contains("words,IntervalTier,0,0,1,a,0.5\n"));
+        let imported = TextGrid::from_long_csv(&csv, 0.0, 3.0).unwrap();
+        assert_eq!(imported.tiers[0].intervals[1].score, Some(1.0));
+        assert_eq!(imported.tiers[0].intervals[2].score, None);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_interval_score_serde_round_trip() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        let mut tier = interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")]);
+        tier.intervals[0].score = Some(0.25);
+        textgrid.add_tier(tier).unwrap();
+        let restored = TextGrid::from_cache_bytes(&textgrid.to_cache_bytes().unwrap()).unwrap();
+        assert_eq!(restored.tiers[0].intervals[0].score, Some(0.25));
+    }
+
+    #[test]
+    fn test_duration_of_labels() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 0.5, "a"), (0.5, 1.5, "b"), (1.5, 2.0, "c"), (2.0, 4.0, "a")])).unwrap();
+        let labels: std::collections::HashSet<String> = ["a".to_string(), "b".to_string()].into();
+
+        assert_eq!(textgrid.get_tier("phones").unwrap().duration_of_labels(&labels), 3.5);
+        textgrid.add_tier(interval_tier("words", 0.0, 4.0, &[(0.0, 1.0, "b")])).unwrap();
+        assert_eq!(textgrid.duration_of_labels(&labels), 4.5);
+    }
+
+    #[test]
+    fn test_adjust_points() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H"), (1.0, "L")])).unwrap();
+        textgrid.tier_adjust_points("tones", |_| 0.01).unwrap();
+        let times: Vec<f64> = textgrid.get_tier("tones").unwrap().points.iter().map(|p| p.time).collect();
+        assert_eq!(times, [0.51, 1.01]);
+
+        assert!(textgrid.tier_adjust_points("tones", |_| 1.5).is_err());
+        assert!(textgrid.tier_adjust_points("tones", |t| 1.0 - t).is_err());
+        assert_eq!(textgrid.get_tier("tones").unwrap().points[0].time, 0.51);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("tones").unwrap().points[0].time, 0.5);
+    }
+
+    #[test]
+    fn test_dedup_points() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H"), (0.5 + 1e-7, "H2"), (1.0, "L")])).unwrap();
+        assert_eq!(textgrid.tier_dedup_points("tones", 1e-6).unwrap(), 1);
+        let marks: Vec<&str> = textgrid.get_tier("tones").unwrap().points.iter().map(|p| p.mark.as_str()).collect();
+        assert_eq!(marks, ["H", "L"]);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("tones").unwrap().points.len(), 3);
+    }
+
+    #[test]
+    fn test_declared_tier_count_mismatch() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 1.0, &[(0.5, "H")])).unwrap();
+
+        for short_format in [false, true] {
+            let path = std::env::temp_dir().join(format!("textgrid_tier_count_{}.TextGrid", short_format));
+            textgrid.to_file(&path, short_format).unwrap();
+            let content = std::fs::read_to_string(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            let (declared, more, fewer) = if short_format {
+                ("\n2\n", "\n3\n", "\n1\n")
+            } else {
+                ("size = 2\n", "size = 3\n", "size = 1\n")
+            };
+
+            let too_few = content.replacen(declared, more, 1);
+            match TextGrid::parse_bytes(too_few.as_bytes()) {
+                Err(TextGridError::Format(message)) => assert_eq!(message, "Expected 3 tiers, found 2"),
+                other => panic!("expected a format error, got {:?}", other),
+            }
+
+            let too_many = content.replacen(declared, fewer, 1);
+            match TextGrid::parse_bytes(too_many.as_bytes()) {
+                Err(TextGridError::Format(message)) => assert!(message.starts_with("File declares 1 tiers but has more data")),
+                other => panic!("expected a format error, got {:?}", other),
+            }
+
+            let trailing_blank = format!("{}\n\n", content);
+            assert_eq!(TextGrid::parse_bytes(trailing_blank.as_bytes()).unwrap().tiers.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_labels() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "sh"), (1.0, 2.0, "SH"), (2.0, 3.0, "Sh"), (3.0, 4.0, "x")])).unwrap();
+        let canonical = ["SH".to_string()];
+        let changed = textgrid.tier_canonicalize_labels("phones", &canonical, |label, symbol| label.eq_ignore_ascii_case(symbol)).unwrap();
+        assert_eq!(changed, 2);
+        let texts: Vec<&str> = textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["SH", "SH", "SH", "x"]);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("phones").unwrap().intervals[0].text, "sh");
+    }
+
+    #[test]
+    fn test_from_file_lenient() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H"), (1.5, "L")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_lenient_test.TextGrid");
+        textgrid.to_file(&path, false).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap().replace("time = 1.5", "time = 2.25");
+        std::fs::write(&path, content).unwrap();
+
+        assert!(TextGrid::from_file(&path).is_err());
+        let (repaired, warnings) = TextGrid::from_file_lenient(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(repaired.get_tier("tones").unwrap().points[1].time, 2.0);
+        assert_eq!(warnings, ["Tier 'tones': point at 2.25 clamped to 2"]);
+    }
+
+    #[test]
+    fn test_matches_schema() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("phones", 0.0, 1.0, &[(0.5, "H")])).unwrap();
+
+        let schema = [("words".to_string(), TierType::IntervalTier), ("phones".to_string(), TierType::PointTier)];
+        assert!(textgrid.matches_schema(&schema).is_ok());
+
+        let schema = [("words".to_string(), TierType::IntervalTier), ("phones".to_string(), TierType::IntervalTier)];
+        match textgrid.matches_schema(&schema) {
+            Err(TextGridError::Format(message)) => assert_eq!(message, "Tier 'phones' should be IntervalTier but is PointTier"),
+            other => panic!("expected a format error, got {:?}", other),
+        }
+        assert!(textgrid.matches_schema(&schema[..1]).is_err());
+    }
+
+    #[test]
+    fn test_collapse_short_intervals() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 1.0, &[(0.0, 0.5, "a"), (0.5, 0.51, "x"), (0.51, 1.0, "b")])).unwrap();
+        assert_eq!(textgrid.tier_collapse_short_intervals("phones", 0.05, CollapseTarget::Previous).unwrap(), 1);
+        let bounds: Vec<(f64, f64, &str)> =
+            textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(bounds, [(0.0, 0.51, "a"), (0.51, 1.0, "b")]);
+
+        textgrid.undo().unwrap();
+        textgrid.tier_collapse_short_intervals("phones", 0.05, CollapseTarget::Next).unwrap();
+        let bounds: Vec<(f64, f64, &str)> =
+            textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(bounds, [(0.0, 0.5, "a"), (0.5, 1.0, "b")]);
+    }
+
+    #[test]
+    fn test_with_tier_mut() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        textgrid
+            .with_tier_mut("words", |tier| {
+                for interval in &mut tier.intervals {
+                    interval.text = interval.text.to_uppercase();
+                }
+                Ok(())
+            })
+            .unwrap();
+        let texts: Vec<&str> = textgrid.get_tier("words").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["A", "B"]);
+
+        let invalid = textgrid.with_tier_mut("words", |tier| {
+            tier.intervals[0].xmax = 1.5;
+            Ok(())
+        });
+        assert!(invalid.is_err());
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals[0].xmax, 1.0);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals[0].text, "a");
+        textgrid.redo().unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals[0].text, "A");
+    }
+
+    #[test]
+    fn test_transcript() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "the"), (1.0, 2.0, ""), (2.0, 3.0, "cat")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(0.5, "H"), (2.5, "L")])).unwrap();
+        assert_eq!(textgrid.transcript("words", " ").unwrap(), "the cat");
+        assert_eq!(textgrid.transcript("tones", "-").unwrap(), "H-L");
+        assert!(textgrid.transcript("missing", " ").is_err());
+    }
+
+    #[test]
+    fn test_float_sizes() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        textgrid.add_tier(interval_tier("empty", 0.0, 1.0, &[])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_float_sizes.TextGrid");
+        textgrid.to_file(&path, false).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let floats = content.replace("\nsize = 2\n", "\nsize = 2.0\n").replace("intervals: size = 0", "intervals: size = 0.0");
+        assert!(floats.contains("size = 2.0") && floats.contains("intervals: size = 0.0"));
+        let parsed = TextGrid::parse_bytes(floats.as_bytes()).unwrap();
+        assert_eq!(parsed.tiers.len(), 2);
+        assert!(parsed.tiers[1].intervals.is_empty());
+
+        let fractional = content.replace("\nsize = 2\n", "\nsize = 1.5\n");
+        assert!(TextGrid::parse_bytes(fractional.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut textgrid = TextGridBuilder::new()
+            .bounds(0.0, 2.0)
+            .tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "a")]))
+            .tier(interval_tier("phones", 0.0, 2.0, &[(0.0, 1.0, "b"), (1.0, 2.0, "c")]))
+            .tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")]))
+            .build()
+            .unwrap();
+        let names: Vec<&str> = textgrid.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words", "phones", "tones"]);
+        assert!(textgrid.undo().is_err());
+
+        let derived = TextGridBuilder::new().tier(point_tier("tones", 0.5, 1.5, &[])).build().unwrap();
+        assert_eq!((derived.xmin, derived.xmax), (0.5, 1.5));
+        assert!(TextGridBuilder::new().bounds(0.0, 1.0).tier(point_tier("tones", 0.0, 2.0, &[])).build().is_err());
+        assert!(TextGridBuilder::new().build().is_err());
+    }
+
+    #[test]
+    fn test_insert_interval_splitting() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 5.0, "old")])).unwrap();
+        let new = Interval { xmin: 2.0, xmax: 3.0, text: "new".to_string(), score: None };
+        textgrid.tier_insert_interval_splitting("words", new).unwrap();
+        let bounds: Vec<(f64, f64, &str)> =
+            textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(bounds, [(0.0, 2.0, "old"), (2.0, 3.0, "new"), (3.0, 5.0, "old")]);
+        assert!(textgrid.get_tier("words").unwrap().validate().is_ok());
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals.len(), 1);
+    }
+
+    #[test]
+    fn test_tier_reports() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 4.0, &[(0.5, 1.5, "a"), (1.5, 2.0, ""), (3.0, 4.0, "b")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 4.0, &[(1.0, "H"), (2.0, "")])).unwrap();
+
+        let reports = textgrid.tier_reports();
+        assert_eq!(
+            reports[0],
+            TierReport {
+                name: "words".to_string(),
+                tier_type: TierType::IntervalTier,
+                item_count: 3,
+                labeled_count: 2,
+                labeled_duration: 2.0,
+                coverage: 0.5,
+                gap_count: 2,
+            }
+        );
+        assert_eq!((reports[1].item_count, reports[1].labeled_count, reports[1].gap_count), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_quoted_value_with_delimiters() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a = b"), (1.0, 2.0, "x")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_quoted_value_test.TextGrid");
+        textgrid.to_file(&path, false).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap().replace("text = \"x\"", "text =   \" x \"");
+        std::fs::remove_file(&path).unwrap();
+
+        let parsed = TextGrid::parse_bytes(content.as_bytes()).unwrap();
+        let texts: Vec<&str> = parsed.tiers[0].intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["a = b", " x "]);
+    }
+
+    #[test]
+    fn test_split_into_single_tier_grids() {
+        let textgrid = TextGridBuilder::new()
+            .bounds(0.0, 2.0)
+            .tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "a")]))
+            .tier(interval_tier("phones", 0.0, 2.0, &[(0.0, 1.0, "b")]))
+            .tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")]))
+            .build()
+            .unwrap();
+        let grids = textgrid.split_into_single_tier_grids();
+        assert_eq!(grids.len(), 3);
+        for (grid, tier) in grids.iter().zip(&textgrid.tiers) {
+            assert_eq!((grid.xmin, grid.xmax), (0.0, 2.0));
+            assert_eq!(grid.tiers.len(), 1);
+            assert_eq!(grid.tiers[0].name, tier.name);
+        }
+    }
+
+    #[test]
+    fn test_snap_boundaries_to_points() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, "c")])).unwrap();
+        textgrid.add_tier(point_tier("marks", 0.0, 3.0, &[(1.02, "x"), (2.5, "y")])).unwrap();
+
+        assert_eq!(textgrid.snap_boundaries_to_points("words", "marks", 0.05).unwrap(), 1);
+        let bounds: Vec<(f64, f64)> = textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax)).collect();
+        assert_eq!(bounds, [(0.0, 1.02), (1.02, 2.0), (2.0, 3.0)]);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals[0].xmax, 1.0);
+        assert!(textgrid.snap_boundaries_to_points("marks", "words", 0.05).is_err());
+    }
+
+    #[test]
+    fn test_validation_options() {
+        let defaults = ValidationOptions::default();
+        let validate = |tiers: Vec<Tier>, options: &ValidationOptions| {
+            validate_textgrid_with(&TextGrid::new(0.0, 3.0).unwrap().with_tiers(tiers), options)
+        };
+
+        let overlapping = vec![interval_tier("words", 0.0, 3.0, &[(0.0, 1.0 + 1e-10, "a"), (1.0, 3.0, "b")])];
+        assert!(validate(overlapping.clone(), &defaults).is_err());
+        assert!(validate(overlapping, &ValidationOptions { overlap_epsilon: 1e-9, ..defaults }).is_ok());
+
+        let gappy = vec![interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (2.0, 3.0, "b")])];
+        assert!(validate(gappy.clone(), &defaults).is_ok());
+        assert!(validate(gappy, &ValidationOptions { require_gapless: true, ..defaults }).is_err());
+        let gapless = vec![interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 3.0, "b")])];
+        assert!(validate(gapless, &ValidationOptions { require_gapless: true, ..defaults }).is_ok());
+
+        let duplicates = vec![point_tier("tones", 0.0, 3.0, &[]), point_tier("tones", 0.0, 3.0, &[])];
+        assert!(validate(duplicates.clone(), &defaults).is_err());
+        assert!(validate(duplicates, &ValidationOptions { allow_duplicate_tier_names: true, ..defaults }).is_ok());
+
+        let narrow = vec![point_tier("tones", 1.0, 2.0, &[])];
+        assert!(validate(narrow.clone(), &defaults).is_ok());
+        assert!(validate(narrow, &ValidationOptions { check_tier_bounds_match: true, ..defaults }).is_err());
+
+        let zero_length = vec![interval_tier("words", 0.0, 3.0, &[(1.0, 1.0, "")])];
+        assert!(validate(zero_length.clone(), &defaults).is_err());
+        assert!(validate(zero_length, &ValidationOptions { forbid_zero_duration: false, ..defaults }).is_ok());
+    }
+
+    #[test]
+    fn test_sample_labels() {
+        let tier = interval_tier("words", 0.0, 0.6, &[(0.0, 0.3, "a"), (0.3, 0.6, "b")]);
+        let samples = tier.sample_labels(0.1).unwrap();
+        let labels: Vec<&str> = samples.iter().map(|(_, label)| label.as_str()).collect();
+        assert_eq!(labels, ["a", "a", "a", "b", "b", "b"]);
+        assert_eq!(samples[1].0, 0.1);
+        assert!(tier.sample_labels(0.0).is_err());
+        assert!(tier.sample_labels(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_annotate_intervals_with_points() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("syllables", 0.0, 2.0, &[(0.0, 1.0, "ba"), (1.0, 2.0, "na")])).unwrap();
+        textgrid.add_tier(point_tier("stress", 0.0, 2.0, &[(1.4, "1")])).unwrap();
+
+        assert_eq!(textgrid.annotate_intervals_with_points("syllables", "stress", "_").unwrap(), 1);
+        let texts: Vec<&str> = textgrid.get_tier("syllables").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["ba", "na_1"]);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("syllables").unwrap().intervals[1].text, "na");
+    }
+
+    #[test]
+    fn test_special_tier_names_round_trip() {
+        let names = ["he said \"x\"", "", " padded "];
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        for name in names {
+            textgrid.add_tier(interval_tier(name, 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        }
+
+        for (file, short_format) in [("long.TextGrid", Some(false)), ("short.TextGrid", Some(true)), ("names.textgridbin", None)] {
+            let path = std::env::temp_dir().join(format!("textgrid_names_{}", file));
+            match short_format {
+                Some(short_format) => textgrid.to_file(&path, short_format).unwrap(),
+                None => textgrid.to_binary_file(&path).unwrap(),
+            }
+            let loaded = TextGrid::from_file(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            let loaded_names: Vec<&str> = loaded.tiers.iter().map(|t| t.name.as_str()).collect();
+            assert_eq!(loaded_names, names);
+        }
+    }
+
+    #[test]
+    fn test_transition_counts() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, ""), (3.0, 4.0, "a")])).unwrap();
+        let counts = textgrid.get_tier("phones").unwrap().transition_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&("a".to_string(), "b".to_string())], 1);
+        assert_eq!(counts[&("b".to_string(), "a".to_string())], 1);
+
+        let phones = textgrid.get_tier("phones").unwrap();
+        assert_eq!(phones.transition_counts_with(BlankHandling::Skip), counts);
+        let broken = phones.transition_counts_with(BlankHandling::BreakSequence);
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[&("a".to_string(), "b".to_string())], 1);
+
+        textgrid.add_tier(interval_tier("more", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        assert_eq!(textgrid.transition_counts()[&("a".to_string(), "b".to_string())], 2);
+        assert_eq!(textgrid.transition_counts_with(BlankHandling::BreakSequence).len(), 1);
+    }
+
+    #[test]
+    fn test_extend_to_bounds() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.5, 1.0, "a"), (1.0, 2.5, "b")])).unwrap();
+        textgrid.add_tier(interval_tier("gappy", 0.0, 3.0, &[(0.5, 1.0, "a"), (2.0, 2.5, "b")])).unwrap();
+
+        textgrid.tier_extend_to_bounds("words").unwrap();
+        let bounds: Vec<(f64, f64)> = textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax)).collect();
+        assert_eq!(bounds, [(0.0, 1.0), (1.0, 3.0)]);
+        assert!(textgrid.tier_extend_to_bounds("gappy").is_err());
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.get_tier("words").unwrap().intervals[0].xmin, 0.5);
+    }
+
+    #[test]
+    fn test_query_cooccurrence() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 2.5, "the"), (2.5, 4.0, "cat")])).unwrap();
+        textgrid.add_tier(interval_tier("pos", 0.0, 4.0, &[(0.0, 2.0, "DT"), (2.0, 4.0, "NN")])).unwrap();
+
+        assert_eq!(textgrid.query_cooccurrence(&[("words", "the"), ("pos", "DT")]), [(1.0, 2.0)]);
+        assert_eq!(textgrid.query_cooccurrence(&[("words", "the"), ("pos", "NN")]), [(2.0, 2.5)]);
+        assert!(textgrid.query_cooccurrence(&[("words", "cat"), ("pos", "DT")]).is_empty());
+        assert!(textgrid.query_cooccurrence(&[("words", "the"), ("missing", "DT")]).is_empty());
+    }
+
+    #[test]
+    fn test_label_duration_distribution() {
+        let tier = interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 1.5, ""), (1.5, 3.0, "b"), (3.0, 4.0, "a")]);
+        let distribution = tier.label_duration_distribution();
+        assert!((distribution.values().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!((distribution["a"] - 2.0 / 3.5).abs() < 1e-12);
+        assert!(!distribution.contains_key(""));
+
+        assert!(interval_tier("empty", 0.0, 1.0, &[(0.0, 1.0, "")]).label_duration_distribution().is_empty());
+    }
+
+    #[test]
+    fn test_write_tiers_to_file() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_selected_tiers.TextGrid");
+
+        textgrid.write_tiers_to_file(&path, &["words"], true).unwrap();
+        let loaded = TextGrid::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.tiers.len(), 1);
+        assert_eq!(loaded.tiers[0].name, "words");
+        assert_eq!((loaded.xmin, loaded.xmax), (0.0, 2.0));
+        assert_eq!(textgrid.tiers.len(), 2);
+
+        assert!(textgrid.write_tiers_to_file(&path, &["missing"], true).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_rebase_to_zero() {
+        let mut textgrid = TextGrid::new(5.0, 10.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 5.0, 10.0, &[(5.0, 7.5, "a"), (7.5, 10.0, "b")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 5.0, 10.0, &[(6.0, "H")])).unwrap();
+
+        textgrid.rebase_to_zero().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (0.0, 5.0));
+        let words = textgrid.get_tier("words").unwrap();
+        assert_eq!((words.xmin, words.xmax, words.intervals[1].xmin), (0.0, 5.0, 2.5));
+        assert_eq!(textgrid.get_tier("tones").unwrap().points[0].time, 1.0);
+        assert!(validate_textgrid(&textgrid).is_ok());
+
+        textgrid.undo().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (5.0, 10.0));
+    }
+
+    #[test]
+    fn test_short_format_unquoted_class() {
+        let content = "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n0\n2\n2\n\
+                       IntervalTier\n\"words\"\n0\n2\n1\n0\n2\n\"a\"\n\
+                       \"TextTier\"\n\"tones\"\n0\n2\n1\n1\n\"H\"\n";
+        let textgrid = TextGrid::parse_bytes(content.as_bytes()).unwrap();
+        assert_eq!(textgrid.tiers[0].tier_type, TierType::IntervalTier);
+        assert_eq!(textgrid.tiers[0].intervals[0].text, "a");
+        assert_eq!(textgrid.tiers[1].tier_type, TierType::PointTier);
+
+        let unquoted_name = content.replace("\"words\"", "words");
+        assert!(TextGrid::parse_bytes(unquoted_name.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_from_reader_with_label_fn() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "the"), (1.0, 2.0, "cat")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "h")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_label_fn_test.TextGrid");
+        textgrid.to_file(&path, false).unwrap();
+        let content = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let upper = TextGrid::from_reader_with_label_fn(content.as_slice(), |label| label.to_uppercase()).unwrap();
+        let texts: Vec<&str> = upper.tiers[0].intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["THE", "CAT"]);
+        assert_eq!(upper.tiers[1].points[0].mark, "H");
+        assert_eq!(upper.tiers[0].name, "words");
+    }
+
+    #[test]
+    fn test_remove_empty_tiers() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, ""), (1.0, 2.0, "a")])).unwrap();
+        textgrid.add_tier(interval_tier("blank", 0.0, 2.0, &[(0.0, 1.0, ""), (1.0, 2.0, " ")])).unwrap();
+
+        assert_eq!(textgrid.empty_tiers(), ["blank"]);
+        assert_eq!(textgrid.remove_empty_tiers(), 1);
+        let names: Vec<&str> = textgrid.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words"]);
+        assert_eq!(textgrid.remove_empty_tiers(), 0);
+
+        textgrid.undo().unwrap();
+        assert_eq!(textgrid.tiers.len(), 2);
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+
+        let mut names = Vec::new();
+        for tier in &textgrid {
+            names.push(tier.name.as_str());
+        }
+        assert_eq!(names, ["words", "tones"]);
+
+        let owned: Vec<Tier> = textgrid.into_iter().collect();
+        assert_eq!(owned.len(), 2);
+        assert_eq!(owned[1].points[0].mark, "H");
+    }
+
+    #[test]
+    fn test_rate() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier(
+            "syllables",
+            0.0,
+            5.0,
+            &[(0.0, 0.5, "a"), (0.5, 1.0, "b"), (1.0, 1.5, "c"), (1.5, 2.0, "d"), (2.0, 2.5, "e"), (2.5, 5.0, "")],
+        ))
+        .unwrap();
+        assert_eq!(textgrid.rate("syllables", RateDenominator::LabeledDuration).unwrap(), 2.0);
+        assert_eq!(textgrid.rate("syllables", RateDenominator::TierDuration).unwrap(), 1.0);
+        assert_eq!(interval_tier("empty", 0.0, 1.0, &[]).rate(RateDenominator::LabeledDuration), 0.0);
+    }
+
+    #[test]
+    fn test_annotated_item_indices() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 2.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_item_indices.TextGrid");
+
+        textgrid.to_file_with_options(&path, false, &TextWriteOptions { annotate_indices: true }).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let loaded = TextGrid::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let items: Vec<&str> = content.lines().filter(|line| line.trim_start().starts_with("item [")).map(str::trim).collect();
+        assert_eq!(items, ["item []:", "item [1]: ! words", "item [2]: ! tones"]);
+        assert_eq!(loaded.tiers.len(), 2);
+
+        let reloaded = textgrid.to_text_string(false).unwrap();
+        let items: Vec<&str> = reloaded.lines().filter(|line| line.trim_start().starts_with("item [")).map(str::trim).collect();
+        assert_eq!(items, ["item []:", "item [1]:", "item [2]:"]);
+        let misnumbered = content.replace("item [2]:", "item [3]:");
+        assert_eq!(TextGrid::parse_bytes(misnumbered.as_bytes()).unwrap().tiers.len(), 2);
+    }
+
+    #[test]
+    fn test_assert_duration() {
+        let textgrid = TextGrid::new(1.0, 6.0).unwrap();
+        assert_eq!(textgrid.bounds_summary(), (1.0, 6.0, 5.0));
+        assert!(textgrid.assert_duration(5.0, 0.01).is_ok());
+        assert!(textgrid.assert_duration(5.005, 0.01).is_ok());
+        assert!(textgrid.assert_duration(5.1, 0.01).is_err());
+        assert!(textgrid.assert_duration(f64::NAN, 0.01).is_err());
+    }
+
+    #[test]
+    fn test_invert_tier() {
+        let mut textgrid = TextGrid::new(0.0, 5.0).unwrap();
+        textgrid.add_tier(interval_tier("silences", 0.0, 5.0, &[(0.0, 1.0, ""), (1.0, 3.0, "sil"), (3.0, 5.0, "")])).unwrap();
+
+        let inverted = textgrid.invert_tier("silences", "speech".to_string(), "speech").unwrap();
+        let spans: Vec<(f64, f64, &str)> = inverted.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.0, 1.0, "speech"), (1.0, 3.0, ""), (3.0, 5.0, "speech")]);
+        assert_eq!(inverted.name, "speech");
+        assert_eq!((inverted.xmin, inverted.xmax), (0.0, 5.0));
+        assert!(textgrid.invert_tier("missing", "x".to_string(), "speech").is_err());
+    }
+
+    #[test]
+    fn test_move_point() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 3.0, &[(0.5, "L"), (1.0, "H"), (2.0, "L%")])).unwrap();
+
+        textgrid.tier_move_point("tones", 0, 1.5).unwrap();
+        let marks: Vec<(f64, &str)> = textgrid.get_tier("tones").unwrap().points.iter().map(|p| (p.time, p.mark.as_str())).collect();
+        assert_eq!(marks, [(1.0, "H"), (1.5, "L"), (2.0, "L%")]);
+
+        assert!(textgrid.tier_move_point("tones", 0, 2.0).is_err());
+        assert!(textgrid.tier_move_point("tones", 0, 4.0).is_err());
+        assert!(textgrid.tier_move_point("tones", 5, 1.0).is_err());
+
+        textgrid.undo().unwrap();
+        let times: Vec<f64> = textgrid.get_tier("tones").unwrap().points.iter().map(|p| p.time).collect();
+        assert_eq!(times, [0.5, 1.0, 2.0]);
+        assert_eq!(Point { time: 1.0, mark: "H".into() }.with_time(2.0).time, 2.0);
+    }
+
+    #[test]
+    fn test_header_whitespace_variants() {
+        let body = "\n0\n1\n1\n\"IntervalTier\"\n\"words\"\n0\n1\n1\n0\n1\n\"a\"\n";
+        for header in ["File type= \"ooTextFile\"\nObject class= \"TextGrid\"", "File type  =  \"ooTextFile\"\nObject class  =  \"TextGrid\""] {
+            let textgrid = TextGrid::parse_bytes(format!("{}{}", header, body).as_bytes()).unwrap();
+            assert_eq!(textgrid.tiers[0].intervals[0].text, "a");
+        }
+        let wrong_type = format!("File type = \"ooBinaryFile\"\nObject class = \"TextGrid\"{}", body);
+        assert!(TextGrid::parse_bytes(wrong_type.as_bytes()).is_err());
+        let wrong_key = format!("Filetype = \"ooTextFile\"\nObject class = \"TextGrid\"{}", body);
+        assert!(TextGrid::parse_bytes(wrong_key.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_build_word_tier_from_phones() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid
+            .add_tier(interval_tier(
+                "phones",
+                0.0,
+                2.0,
+                &[(0.0, 0.2, ""), (0.2, 0.4, "h"), (0.4, 0.6, "a"), (0.6, 0.8, ""), (0.8, 1.0, "d"), (1.0, 1.3, "o"), (1.3, 1.6, "g")],
+            ))
+            .unwrap();
+        let words = vec![("ha".to_string(), 2), ("dog".to_string(), 3)];
+
+        textgrid.build_word_tier_from_phones("phones", &words, "words".to_string()).unwrap();
+        let spans: Vec<(f64, f64, &str)> = textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.2, 0.6, "ha"), (0.8, 1.6, "dog")]);
+
+        textgrid.undo().unwrap();
+        assert!(textgrid.get_tier("words").is_none());
+        let short = vec![("ha".to_string(), 2), ("dog".to_string(), 2)];
+        assert!(textgrid.build_word_tier_from_phones("phones", &short, "words".to_string()).is_err());
+        assert!(textgrid.get_tier("words").is_none());
+    }
+
+    #[test]
+    fn test_label_length_checks() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "short"), (1.0, 2.0, "much too long")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+
+        assert_eq!(textgrid.find_overlong_labels(8), [("words", 1, 13)]);
+        let message = textgrid.check_label_lengths(8).unwrap_err().to_string();
+        assert!(message.contains("words") && message.contains("13"));
+        assert!(textgrid.check_label_lengths(13).is_ok());
+    }
+
+    #[test]
+    fn test_pad_intervals() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid
+            .add_tier(interval_tier("words", 0.0, 3.0, &[(0.05, 1.0, "a"), (1.0, 1.5, "b"), (1.7, 2.9, "c")]))
+            .unwrap();
+        let spans = |textgrid: &TextGrid| -> Vec<(f64, f64)> {
+            textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax)).collect()
+        };
+
+        textgrid.tier_pad_intervals("words", 0.25).unwrap();
+        assert_eq!(spans(&textgrid), [(0.0, 1.0), (1.0, 1.6), (1.6, 3.0)]);
+        assert!(validate_textgrid(&textgrid).is_ok());
+
+        textgrid.undo().unwrap();
+        assert_eq!(spans(&textgrid), [(0.05, 1.0), (1.0, 1.5), (1.7, 2.9)]);
+        assert!(textgrid.tier_pad_intervals("words", -0.3).is_err());
+        assert_eq!(spans(&textgrid), [(0.05, 1.0), (1.0, 1.5), (1.7, 2.9)]);
+    }
+
+    #[test]
+    fn test_trailing_data_after_last_tier() {
+        let mut textgrid = TextGrid::new(0.0, 1.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_trailing_data.TextGrid");
+
+        for short_format in [false, true] {
+            textgrid.to_file(&path, short_format).unwrap();
+            let content = std::fs::read_to_string(&path).unwrap();
+            let trailing = format!("{}\n\n! exported by aligner 2.1\n   ! checksum 1234\nexported-by: aligner\n\n", content);
+            let parsed = TextGrid::parse_bytes(trailing.as_bytes()).unwrap();
+            assert_eq!(parsed.tiers.len(), 1);
+            assert_eq!(parsed.tiers[0].intervals[0].text, "a");
+        }
+
+        textgrid.to_binary_file(&path).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(TextGrid::parse_bytes(&bytes).is_ok());
+        assert!(TextGrid::parse_binary_with_trailing(&bytes).unwrap().1.is_empty());
+        bytes.extend_from_slice(b"junk");
+        assert_eq!(TextGrid::parse_bytes(&bytes).unwrap().tiers.len(), 1);
+        let (parsed, trailing) = TextGrid::parse_binary_with_trailing(&bytes).unwrap();
+        assert_eq!(parsed.tiers.len(), 1);
+        assert_eq!(trailing, b"junk");
+    }
+
+    #[test]
+    fn test_labeled_region_jaccard() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("a", 0.0, 4.0, &[(0.0, 1.0, ""), (1.0, 2.0, "x"), (2.0, 3.0, "y"), (3.0, 4.0, "")])).unwrap();
+        textgrid.add_tier(interval_tier("b", 0.0, 4.0, &[(0.0, 2.0, ""), (2.0, 4.0, "speech")])).unwrap();
+        textgrid.add_tier(interval_tier("silent", 0.0, 4.0, &[(0.0, 4.0, "")])).unwrap();
+        textgrid.add_tier(interval_tier("quiet", 0.0, 4.0, &[])).unwrap();
+
+        // Labeled time is [1, 3) and [2, 4): intersection 1 s, union 3 s.
+        let jaccard = textgrid.labeled_region_jaccard("a", "b").unwrap();
+        assert!((jaccard - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(textgrid.labeled_region_jaccard("a", "a").unwrap(), 1.0);
+        assert_eq!(textgrid.labeled_region_jaccard("a", "silent").unwrap(), 0.0);
+        assert_eq!(textgrid.labeled_region_jaccard("silent", "quiet").unwrap(), 1.0);
+        assert!(textgrid.labeled_region_jaccard("a", "missing").is_err());
+    }
+
+    #[test]
+    fn test_tier_from_sample_boundaries() {
+        let boundaries = vec![(0, 8000, "a".to_string()), (8000, 24000, "b".to_string())];
+        let tier = Tier::from_sample_boundaries("words", &boundaries, 16000.0, 0.0, 2.0).unwrap();
+        let spans: Vec<(f64, f64, &str)> = tier.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.0, 0.5, "a"), (0.5, 1.5, "b")]);
+        assert_eq!(tier.tier_type, TierType::IntervalTier);
+
+        assert!(Tier::from_sample_boundaries("words", &boundaries, 0.0, 0.0, 2.0).is_err());
+        assert!(Tier::from_sample_boundaries("words", &boundaries, 16000.0, 0.0, 1.0).is_err());
+        let overlapping = vec![(0, 9000, "a".to_string()), (8000, 24000, "b".to_string())];
+        assert!(Tier::from_sample_boundaries("words", &overlapping, 16000.0, 0.0, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_keep_labels() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, ""), (3.0, 4.0, "b")])).unwrap();
+        let keep: std::collections::HashSet<String> = ["a".to_string()].into_iter().collect();
+
+        assert_eq!(textgrid.tier_keep_labels("phones", &keep).unwrap(), 2);
+        let texts: Vec<&str> = textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["a", "", "", ""]);
+
+        textgrid.undo().unwrap();
+        let texts: Vec<&str> = textgrid.get_tier("phones").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["a", "b", "", "b"]);
+    }
+
+    #[test]
+    fn test_point_boundary_deviations() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, "c")])).unwrap();
+        textgrid.add_tier(point_tier("marks", 0.0, 3.0, &[(1.0, "x"), (2.05, "y")])).unwrap();
+
+        let deviations = textgrid.point_boundary_deviations("marks", "words").unwrap();
+        assert_eq!(deviations.len(), 2);
+        assert_eq!(deviations[0], (1.0, 0.0));
+        assert_eq!(deviations[1].0, 2.05);
+        assert!((deviations[1].1 - 0.05).abs() < 1e-9);
+        assert!(textgrid.point_boundary_deviations("words", "marks").is_err());
+    }
+
+    #[test]
+    fn test_merge_point_tiers() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(point_tier("a", 0.0, 3.0, &[(0.5, "L"), (2.0, "H")])).unwrap();
+        textgrid.add_tier(point_tier("b", 0.0, 3.0, &[(1.0, "M"), (2.01, "H*")])).unwrap();
+
+        textgrid.merge_point_tiers("a", "b", "merged".to_string(), 0.02).unwrap();
+        let marks: Vec<(f64, &str)> = textgrid.get_tier("merged").unwrap().points.iter().map(|p| (p.time, p.mark.as_str())).collect();
+        assert_eq!(marks, [(0.5, "L"), (1.0, "M"), (2.0, "H")]);
+
+        textgrid.undo().unwrap();
+        assert!(textgrid.get_tier("merged").is_none());
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[])).unwrap();
+        assert!(textgrid.merge_point_tiers("a", "words", "merged".to_string(), 0.02).is_err());
+    }
+
+    #[test]
+    fn test_cloned_accessors_are_independent() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+
+        let mut tiers = textgrid.tiers_cloned();
+        let mut intervals = textgrid.tiers[0].intervals_cloned();
+        let mut points = textgrid.tiers[1].points_cloned();
+        tiers[0].name = "renamed".to_string();
+        intervals[0].text = "changed".to_string();
+        points[0].time = 1.5;
+        textgrid.tiers[0].intervals.clear();
+
+        assert_eq!(textgrid.tiers[0].name, "words");
+        assert!(textgrid.tiers[0].intervals.is_empty());
+        assert_eq!(tiers[0].intervals[0].text, "a");
+        assert_eq!(intervals[0].text, "changed");
+        assert_eq!(textgrid.tiers[1].points[0].time, 1.0);
+        assert_eq!(points[0].time, 1.5);
+    }
+
+    #[test]
+    fn test_longest_and_shortest_interval() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 2.0, &[(0.0, 0.1, "a"), (0.1, 0.6, "b"), (0.6, 0.8, "c"), (0.8, 2.0, "")])).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 0.8, "abc")])).unwrap();
+
+        let tier = textgrid.get_tier("phones").unwrap();
+        assert_eq!(tier.longest_interval().unwrap().text, "b");
+        assert_eq!(tier.shortest_interval().unwrap().text, "a");
+
+        let (name, longest) = textgrid.longest_interval().unwrap();
+        assert_eq!((name, longest.text.as_str()), ("words", "abc"));
+        let (name, shortest) = textgrid.shortest_interval().unwrap();
+        assert_eq!((name, shortest.text.as_str()), ("phones", "a"));
+        assert!(TextGrid::new(0.0, 1.0).unwrap().longest_interval().is_none());
+    }
+
+    #[test]
+    fn test_empty_policy_treats_silence_tokens_as_empty() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "sil"), (1.0, 2.0, "a"), (2.0, 3.0, "b"), (3.0, 4.0, " sp ")])).unwrap();
+        let policy = EmptyPolicy::Tokens(["sil".to_string(), "sp".to_string()].into_iter().collect());
+
+        let tier = textgrid.get_tier("phones").unwrap();
+        assert_eq!(tier.speech_extent(), Some((0.0, 4.0)));
+        assert_eq!(tier.speech_extent_with(&policy), Some((1.0, 3.0)));
+        assert_eq!(tier.labeled_duration(), 4.0);
+        assert_eq!(tier.labeled_duration_with(&policy), 2.0);
+        assert_eq!(tier.labeled_duration_before_with(2.5, &policy), 1.5);
+        assert_eq!(tier.transcript_with(" ", &policy), "a b");
+        assert_eq!(textgrid.transcript_with("phones", " ", &policy).unwrap(), "a b");
+        assert!(EmptyPolicy::common_silence().treats_as_empty("<p:>"));
+
+        textgrid.trim_to_speech_with(&policy).unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (1.0, 3.0));
+        textgrid.undo().unwrap();
+        assert_eq!((textgrid.xmin, textgrid.xmax), (0.0, 4.0));
+
+        textgrid.add_tier(interval_tier("words", 0.0, 4.0, &[(0.0, 4.0, "ab")])).unwrap();
+        assert_eq!(textgrid.labeled_region_jaccard("phones", "words").unwrap(), 1.0);
+        assert_eq!(textgrid.labeled_region_jaccard_with("phones", "words", &policy).unwrap(), 0.5);
+        assert_eq!(textgrid.pairwise_labeled_overlap_with(&policy)[0], [2.0, 2.0]);
+        let inverted = textgrid.invert_tier_with("phones", "pauses".to_string(), "pause", &policy).unwrap();
+        let spans: Vec<(f64, f64, &str)> = inverted.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.0, 1.0, "pause"), (1.0, 3.0, ""), (3.0, 4.0, "pause")]);
+    }
+
+    #[test]
+    fn test_pairwise_labeled_overlap() {
+        let mut textgrid = TextGrid::new(0.0, 4.0).unwrap();
+        textgrid.add_tier(interval_tier("a", 0.0, 4.0, &[(0.0, 1.0, ""), (1.0, 3.0, "x"), (3.0, 4.0, "")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 4.0, &[(1.0, "H")])).unwrap();
+        textgrid.add_tier(interval_tier("b", 0.0, 4.0, &[(0.0, 2.5, ""), (2.5, 4.0, "y")])).unwrap();
+        textgrid.add_tier(interval_tier("c", 0.0, 4.0, &[(0.0, 0.5, "z"), (0.5, 4.0, "")])).unwrap();
+
+        let names: Vec<&str> = textgrid.interval_tiers().iter().map(|tier| tier.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        let matrix = textgrid.pairwise_labeled_overlap();
+        assert_eq!(matrix.len(), 3);
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, overlap) in row.iter().enumerate() {
+                assert_eq!(*overlap, matrix[j][i]);
+            }
+        }
+        assert_eq!([matrix[0][0], matrix[1][1], matrix[2][2]], [2.0, 1.5, 0.5]);
+        assert_eq!(matrix[0][1], 0.5);
+        assert_eq!(matrix[0][2], 0.0);
+    }
+
+    #[test]
+    fn test_quotes_and_newlines_round_trip() {
+        let labels = ["he said \"hi\"", "line one\nline two", "", "\"", "x = \"y\"\n\"", "trailing \n"];
+        let mut textgrid = TextGrid::new(0.0, 6.0).unwrap();
+        let intervals: Vec<(f64, f64, &str)> = labels.iter().enumerate().map(|(i, label)| (i as f64, i as f64 + 1.0, *label)).collect();
+        let points: Vec<(f64, &str)> = labels.iter().enumerate().map(|(i, label)| (i as f64 + 0.5, *label)).collect();
+        textgrid.add_tier(interval_tier("the \"words\"", 0.0, 6.0, &intervals)).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 6.0, &points)).unwrap();
+
+        for short_format in [false, true] {
+            let path = std::env::temp_dir().join(format!("textgrid_quote_round_trip_{}.TextGrid", short_format));
+            textgrid.to_file(&path, short_format).unwrap();
+            let content = std::fs::read_to_string(&path).unwrap();
+            let loaded = TextGrid::from_file(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            assert!(content.contains("\"he said \"\"hi\"\"\"\n"));
+            assert_eq!(loaded.tiers[0].name, "the \"words\"");
+            let texts: Vec<&str> = loaded.tiers[0].intervals.iter().map(|i| i.text.as_str()).collect();
+            let marks: Vec<&str> = loaded.tiers[1].points.iter().map(|p| p.mark.as_str()).collect();
+            assert_eq!(texts, labels);
+            assert_eq!(marks, labels);
+        }
+
+        let unterminated = "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n0\n1\n1\n\"IntervalTier\"\n\"words\"\n0\n1\n1\n0\n1\n\"open\n";
+        assert!(TextGrid::parse_bytes(unterminated.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_atomic_writes() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        let dir = std::env::temp_dir().join(format!("textgrid_atomic_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let text_path = dir.join("out.TextGrid");
+        let binary_path = dir.join("out.textgridbin");
+        std::fs::write(&text_path, "stale contents").unwrap();
+
+        textgrid.to_file_atomic(&text_path, false).unwrap();
+        textgrid.to_binary_file_atomic(&binary_path).unwrap();
+        let text = TextGrid::from_file(&text_path).unwrap();
+        let binary = TextGrid::from_file(&binary_path).unwrap();
+        let mut entries: Vec<String> = std::fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name().to_string_lossy().into_owned()).collect();
+        entries.sort();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(text.tiers[0].intervals.len(), 2);
+        assert_eq!(binary.tiers[0].intervals[1].text, "b");
+        assert_eq!(entries, ["out.TextGrid", "out.textgridbin"]);
+    }
+
+    #[test]
+    fn test_to_midpoint_points() {
+        let tier = interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, ""), (2.0, 3.0, "b")]);
+        let midpoints = tier.to_midpoint_points().unwrap();
+        assert_eq!(midpoints.tier_type, TierType::PointTier);
+        let marks: Vec<(f64, &str)> = midpoints.points.iter().map(|p| (p.time, p.mark.as_str())).collect();
+        assert_eq!(marks, [(0.5, "a"), (2.5, "b")]);
+        assert!(midpoints.to_midpoint_points().is_err());
+    }
+
+    #[test]
+    fn test_parser_unescapes_doubled_quotes() {
+        let cases = [
+            ("\"he said \"\"hi\"\"\"", "he said \"hi\""),
+            ("\"a \"\" b\"", "a \" b"),
+            ("\"a \"\"\"\" b\"", "a \"\" b"),
+            ("\"\"\"start\"", "\"start"),
+            ("\"end\"\"\"", "end\""),
+            ("\"\"", ""),
+        ];
+        for (quoted, expected) in cases {
+            let long = format!(
+                "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\nxmin = 0\nxmax = 1\ntiers? <exists>\nsize = 1\nitem []:\n    item [1]:\n        class = \"IntervalTier\"\n        name = \"words\"\n        xmin = 0\n        xmax = 1\n        intervals: size = 1\n        intervals [1]:\n            xmin = 0\n            xmax = 1\n            text = {}\n",
+                quoted
+            );
+            let short = format!(
+                "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n0\n1\n1\n\"TextTier\"\n\"tones\"\n0\n1\n1\n0.5\n{}\n",
+                quoted
+            );
+            assert_eq!(TextGrid::parse_bytes(long.as_bytes()).unwrap().tiers[0].intervals[0].text, expected);
+            assert_eq!(TextGrid::parse_bytes(short.as_bytes()).unwrap().tiers[0].points[0].mark, expected);
+        }
+    }
+
+    #[test]
+    fn test_windows_line_endings_and_bom() {
+        let long = "\u{feff}File type = \"ooTextFile\"\r\nObject class = \"TextGrid\"  \r\n\r\nxmin = 0 \r\nxmax = 1 \r\ntiers? <exists> \r\nsize = 1 \r\nitem []: \r\n    item [1]:\r\n        class = \"IntervalTier\" \r\n        name = \"words\" \r\n        xmin = 0 \r\n        xmax = 1 \r\n        intervals: size = 1 \r\n        intervals [1]:\r\n            xmin = 0 \r\n            xmax = 1 \r\n            text = \"a\" \r\n";
+        let short = "\u{feff}File type = \"ooTextFile\"\r\nObject class = \"TextGrid\"\r\n\r\n0\r\n1\r\n1\r\n\"IntervalTier\"\r\n\"words\"\r\n0\r\n1\r\n1\r\n0\r\n1\r\n\"a\"\r\n";
+        for (content, short_format) in [(long, false), (short, true)] {
+            let textgrid = TextGrid::parse_bytes(content.as_bytes()).unwrap();
+            assert_eq!(textgrid.tiers[0].name, "words");
+            assert_eq!(textgrid.tiers[0].intervals[0].text, "a");
+
+            let path = std::env::temp_dir().join(format!("textgrid_crlf_bom_{}.TextGrid", short_format));
+            std::fs::write(&path, content).unwrap();
+            let loaded = TextGrid::from_file(&path);
+            let detected = parser::detect_short_format(&path);
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(loaded.unwrap().tiers[0].intervals[0].text, "a");
+            assert_eq!(detected.unwrap(), short_format);
+        }
+    }
+
+    #[test]
+    fn test_labeled_duration_before() {
+        let tier = interval_tier("words", 0.0, 4.0, &[(0.0, 1.0, "a"), (1.0, 2.0, ""), (2.0, 4.0, "b")]);
+        assert_eq!(tier.labeled_duration_before(0.0), 0.0);
+        assert_eq!(tier.labeled_duration_before(1.5), 1.0);
+        assert_eq!(tier.labeled_duration_before(3.0), 2.0);
+        assert_eq!(tier.labeled_duration_before(10.0), 3.0);
+    }
+
+    #[test]
+    fn test_resize_interval_moves_neighbors() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, "c")])).unwrap();
+        let spans = |textgrid: &TextGrid| -> Vec<(f64, f64)> {
+            textgrid.get_tier("words").unwrap().intervals.iter().map(|i| (i.xmin, i.xmax)).collect()
+        };
+
+        textgrid.tier_resize_interval("words", 1, 0.8, 2.5).unwrap();
+        assert_eq!(spans(&textgrid), [(0.0, 0.8), (0.8, 2.5), (2.5, 3.0)]);
+        assert!(textgrid.tier_resize_interval("words", 1, 0.8, 3.0).is_err());
+        assert!(textgrid.tier_resize_interval("words", 1, 0.0, 2.5).is_err());
+        assert!(textgrid.tier_resize_interval("words", 1, 2.0, 1.0).is_err());
+
+        textgrid.undo().unwrap();
+        assert_eq!(spans(&textgrid), [(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_truncated_binary_is_a_format_error() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "hello"), (1.0, 2.0, "world")])).unwrap();
+        let path = std::env::temp_dir().join("textgrid_truncated.textgridbin");
+        textgrid.to_binary_file(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+
+        // Cut inside the second interval's text, then at every other length.
+        std::fs::write(&path, &bytes[..bytes.len() - 3]).unwrap();
+        let truncated = read_binary(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(truncated, Err(TextGridError::Format(message)) if message.starts_with("Unexpected end of binary data")));
+        for len in 0..bytes.len() {
+            assert!(matches!(TextGrid::parse_bytes(&bytes[..len]), Err(TextGridError::Format(_))), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_from_str_both_formats() {
+        use std::str::FromStr;
+
+        let long = "File type = \"ooTextFile\"
+Object class = \"TextGrid\"
+
+xmin = 0
+xmax = 2
+tiers? <exists>
+size = 1
+item []:
+    item [1]:
+        class = \"IntervalTier\"
+        name = \"words\"
+        xmin = 0
+        xmax = 2
+        intervals: size = 2
+        intervals [1]:
+            xmin = 0
+            xmax = 1
+            text = \"hello\"
+        intervals [2]:
+            xmin = 1
+            xmax = 2
+            text = \"world\"
+";
+        let short = "File type = \"ooTextFile\"
+Object class = \"TextGrid\"
+
+0
+2
+1
+\"TextTier\"
+\"tones\"
+0
+2
+1
+0.5
+\"H\"
+";
+        let from_long = TextGrid::from_str(long).unwrap();
+        let texts: Vec<&str> = from_long.tiers[0].intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["hello", "world"]);
+        let from_short: TextGrid = short.parse().unwrap();
+        assert_eq!(from_short.tiers[0].tier_type, TierType::PointTier);
+        assert_eq!(from_short.tiers[0].points[0].mark, "H");
+        assert!("not a textgrid".parse::<TextGrid>().is_err());
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dir = std::env::temp_dir().join(format!("textgrid_from_dir_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut first = TextGrid::new(0.0, 1.0).unwrap();
+        first.add_tier(interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")])).unwrap();
+        let mut second = TextGrid::new(0.0, 2.0).unwrap();
+        second.add_tier(point_tier("tones", 0.0, 2.0, &[(1.0, "H")])).unwrap();
+        first.to_file(dir.join("first.TextGrid"), false).unwrap();
+        second.to_file(dir.join("second.textgrid"), true).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a textgrid").unwrap();
+
+        let loaded = TextGrid::from_dir(&dir).unwrap();
+        std::fs::write(dir.join("broken.TextGrid"), "garbage").unwrap();
+        let with_broken = TextGrid::from_dir(&dir).unwrap();
+        std::fs::remove_file(dir.join("first.TextGrid")).unwrap();
+        std::fs::remove_file(dir.join("second.textgrid")).unwrap();
+        let only_broken = TextGrid::from_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.keys().collect::<Vec<_>>(), ["first", "second"]);
+        assert_eq!(loaded["first"].tiers[0].intervals[0].text, "a");
+        assert_eq!(loaded["second"].tiers[0].points[0].mark, "H");
+        assert_eq!(with_broken.len(), 2);
+        assert!(matches!(only_broken, Err(TextGridError::Format(message)) if message.contains("broken.TextGrid")));
+    }
+
+    #[test]
+    fn test_to_text_string_round_trip() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a \"b\""), (1.0, 2.0, "")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H")])).unwrap();
+
+        for short_format in [false, true] {
+            let text = textgrid.to_text_string(short_format).unwrap();
+            assert!(text.starts_with("File type = \"ooTextFile\"\n"));
+            let parsed: TextGrid = text.parse().unwrap();
+            assert!(parsed.approx_eq(&textgrid, 0.0));
+            assert_eq!(parsed.tiers[0].intervals[0].text, "a \"b\"");
+        }
+        let mut socket = std::io::Cursor::new(Vec::new());
+        textgrid.write_to(&mut socket, true).unwrap();
+        assert_eq!(String::from_utf8(socket.into_inner()).unwrap(), textgrid.to_text_string(true).unwrap());
+    }
+
+    #[test]
+    fn test_label_duration_stats() {
+        let tier = interval_tier("phones", 0.0, 2.0, &[(0.0, 0.2, "a"), (0.2, 0.5, "b"), (0.5, 0.9, "a"), (0.9, 2.0, "")]);
+        let stats = tier.label_duration_stats();
+        assert_eq!(stats.len(), 2);
+        let (count, mean, std_dev) = stats["a"];
+        assert_eq!(count, 2);
+        assert!((mean - 0.3).abs() < 1e-12);
+        assert!((std_dev - 0.1).abs() < 1e-12);
+        let (count, mean, std_dev) = stats["b"];
+        assert_eq!(count, 1);
+        assert!((mean - 0.3).abs() < 1e-12);
+        assert_eq!(std_dev, 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 2.0, &[(0.5, "H")])).unwrap();
+
+        let json = serde_json::to_string(&textgrid).unwrap();
+        assert!(json.contains("\"tier_type\":\"IntervalTier\"") && json.contains("\"tier_type\":\"PointTier\""));
+        assert!(!json.contains("history"));
+        let mut restored: TextGrid = serde_json::from_str(&json).unwrap();
+        assert!(restored.approx_eq(&textgrid, 0.0));
+        assert!(restored.undo().is_err());
+        restored.tier_add_point("tones", Point { time: 1.5, mark: "L".into() }).unwrap();
+        restored.undo().unwrap();
+        assert_eq!(restored.tiers[1].points.len(), 1);
+    }
+
+    #[test]
+    fn test_number_points() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(point_tier("marks", 0.0, 3.0, &[(0.5, "H"), (1.5, ""), (2.5, "L")])).unwrap();
+
+        textgrid.tier_number_points("marks", "p").unwrap();
+        let marks: Vec<&str> = textgrid.get_tier("marks").unwrap().points.iter().map(|p| p.mark.as_str()).collect();
+        assert_eq!(marks, ["p1", "p2", "p3"]);
+
+        textgrid.undo().unwrap();
+        let marks: Vec<&str> = textgrid.get_tier("marks").unwrap().points.iter().map(|p| p.mark.as_str()).collect();
+        assert_eq!(marks, ["H", "", "L"]);
+        assert!(textgrid.tier_number_points("missing", "p").is_err());
+    }
+
+    #[test]
+    fn test_difference_tier() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("ref", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b"), (2.0, 3.0, "c")])).unwrap();
+        textgrid.add_tier(interval_tier("hyp", 0.0, 3.0, &[(0.0, 1.2, "a"), (1.2, 2.0, "b"), (2.0, 3.0, "c")])).unwrap();
+
+        let difference = textgrid.difference_tier("ref", "hyp", "diff".to_string()).unwrap();
+        let spans: Vec<(f64, f64, &str)> = difference.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.0, 1.0, "match"), (1.0, 1.2, "diff"), (1.2, 2.0, "match"), (2.0, 3.0, "match")]);
+        assert_eq!(difference.name, "diff");
+        assert!(difference.validate().is_ok());
+        assert!(textgrid.difference_tier("ref", "missing", "diff".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_to_webvtt() {
+        let mut textgrid = TextGrid::new(0.0, 7300.0).unwrap();
+        textgrid
+            .add_tier(interval_tier(
+                "words",
+                0.0,
+                7300.0,
+                &[(0.0, 0.0004, "tiny"), (0.0004, 1.2345, "a < b"), (1.2345, 3.0, "  "), (3.0, 7263.5, ""), (7263.5, 7299.9996, "late")],
+            ))
+            .unwrap();
+        textgrid.add_tier(point_tier("tones", 0.0, 7300.0, &[(1.0, "H")])).unwrap();
+
+        let vtt = textgrid.to_webvtt("words").unwrap();
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.235\na &lt; b\n\n02:01:03.500 --> 02:01:40.000\nlate\n"
+        );
+        assert!(matches!(textgrid.to_webvtt("tones"), Err(TextGridError::Format(_))));
+        assert!(matches!(textgrid.to_webvtt("missing"), Err(TextGridError::Format(_))));
+    }
+
+    #[test]
+    fn test_audacity_labels_round_trip() {
+        let tier = interval_tier("words", 0.0, 3.0, &[(0.0, 1.25, "hello"), (1.25, 2.5, "big\tworld"), (2.5, 3.0, "")]);
+        let labels = tier.to_audacity_labels();
+        assert_eq!(labels, "0.000000\t1.250000\thello\n1.250000\t2.500000\tbig\tworld\n2.500000\t3.000000\t\n");
+
+        let imported = Tier::from_audacity_labels("words", &labels).unwrap();
+        assert!(imported.approx_eq(&tier, 0.0));
+        let with_spectral = "0.5\t1.0\ta\n\\\t100.0\t2000.0\n\n1.0\t2.0\n";
+        let imported = Tier::from_audacity_labels("words", with_spectral).unwrap();
+        let spans: Vec<(f64, f64, &str)> = imported.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(0.5, 1.0, "a"), (1.0, 2.0, "")]);
+        assert_eq!((imported.xmin, imported.xmax), (0.0, 2.0));
+
+        let points = point_tier("beats", 0.0, 2.0, &[(0.5, "x"), (2.0, "y")]);
+        let imported = Tier::from_audacity_labels_with("beats", &points.to_audacity_labels(), true).unwrap();
+        assert!(imported.approx_eq(&points, 0.0));
+        assert!(Tier::from_audacity_labels("beats", &points.to_audacity_labels()).is_err());
+        assert!(Tier::from_audacity_labels("words", "2.0\t1.0\tbackwards\n").is_err());
+        assert!(Tier::from_audacity_labels_with("words", "1.0\t2.0\trange\n", true).is_err());
+    }
+
+    #[test]
+    fn test_canonicalize_boundaries() {
+        let mut tg = TextGrid::new(0.0, 4.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 4.0, &[(0.0, 1.0000000001, "a"), (0.9999999999, 2.0, "b"), (2.0, 2.9999999999, "c"), (3.0000000001, 4.0, "d")])).unwrap();
+        tg.add_tier(interval_tier("phones", 0.0, 4.0, &[(0.0, 1.0, "x"), (1.5, 4.0, "y")])).unwrap();
+        assert!(validate_textgrid(&tg).is_err());
+
+        assert_eq!(tg.canonicalize_boundaries(1e-6), 2);
+        let words = tg.get_tier("words").unwrap();
+        assert_eq!(words.intervals[1].xmin, words.intervals[0].xmax);
+        assert_eq!(words.intervals[3].xmin, words.intervals[2].xmax);
+        assert_eq!(tg.get_tier("phones").unwrap().intervals[1].xmin, 1.5);
+        assert!(validate_textgrid(&tg).is_ok());
+        assert_eq!(tg.canonicalize_boundaries(1e-6), 0);
+
+        tg.undo().unwrap();
+        assert_eq!(tg.get_tier("words").unwrap().intervals[1].xmin, 0.9999999999);
+    }
+
+    #[test]
+    fn test_is_gapless() {
+        let gapless = interval_tier("full", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0000000001, "b"), (2.0, 3.0, "c")]);
+        let internal_gap = interval_tier("gap", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.5, 3.0, "b")]);
+        let short = interval_tier("short", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.5, "b")]);
+        assert!(gapless.is_gapless(1e-6));
+        assert!(!gapless.is_gapless(0.0));
+        assert!(!internal_gap.is_gapless(1e-6));
+        assert!(!short.is_gapless(1e-6));
+        assert!(!point_tier("marks", 0.0, 3.0, &[(1.0, "x")]).is_gapless(1e-6));
+
+        let mut tg = TextGrid::new(0.0, 3.0).unwrap();
+        for tier in [gapless, internal_gap, short, point_tier("marks", 0.0, 3.0, &[(1.0, "x")])] {
+            tg.add_tier(tier).unwrap();
+        }
+        assert_eq!(tg.non_gapless_tiers(1e-6), ["gap", "short"]);
+    }
+
+    #[test]
+    fn test_extract_region() {
+        let mut tg = TextGrid::new(0.0, 5.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 5.0, &[(0.0, 1.5, "a"), (1.5, 3.0, "b"), (3.0, 5.0, "c")])).unwrap();
+        tg.add_tier(point_tier("marks", 0.0, 5.0, &[(0.5, "x"), (1.0, "y"), (2.0, "z"), (4.0, "w")])).unwrap();
+
+        let region = tg.extract_region(1.0, 2.0).unwrap();
+        assert_eq!((region.xmin, region.xmax), (1.0, 2.0));
+        let words = region.get_tier("words").unwrap();
+        assert_eq!((words.xmin, words.xmax), (1.0, 2.0));
+        let spans: Vec<(f64, f64, &str)> = words.intervals.iter().map(|i| (i.xmin, i.xmax, i.text.as_str())).collect();
+        assert_eq!(spans, [(1.0, 1.5, "a"), (1.5, 2.0, "b")]);
+        let marks: Vec<&str> = region.get_tier("marks").unwrap().points.iter().map(|p| p.mark.as_str()).collect();
+        assert_eq!(marks, ["y", "z"]);
+        assert!(validate_textgrid(&region).is_ok());
+        assert_eq!(tg.get_tier("words").unwrap().intervals.len(), 3);
+
+        assert!(tg.extract_region(2.0, 2.0).is_err());
+        assert!(tg.extract_region(3.0, 1.0).is_err());
+        assert!(tg.extract_region(-1.0, 2.0).is_err());
+        assert!(tg.extract_region(4.0, 6.0).is_err());
+    }
+
+    #[test]
+    fn test_shift_time() {
+        let mut tg = TextGrid::new(0.5, 3.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.5, 3.0, &[(0.5, 1.0, "a"), (1.0, 3.0, "b")])).unwrap();
+        tg.add_tier(point_tier("marks", 0.5, 3.0, &[(2.0, "x")])).unwrap();
+
+        tg.shift_time(1.25).unwrap();
+        assert_eq!((tg.xmin, tg.xmax), (1.75, 4.25));
+        let words = tg.get_tier("words").unwrap();
+        assert_eq!((words.xmin, words.xmax), (1.75, 4.25));
+        assert_eq!((words.intervals[1].xmin, words.intervals[1].xmax), (2.25, 4.25));
+        assert_eq!(tg.get_tier("marks").unwrap().points[0].time, 3.25);
+        assert!(validate_textgrid(&tg).is_ok());
+
+        tg.undo().unwrap();
+        assert_eq!((tg.xmin, tg.xmax), (0.5, 3.0));
+        assert_eq!(tg.get_tier("words").unwrap().intervals[0].xmin, 0.5);
+        assert_eq!(tg.get_tier("marks").unwrap().points[0].time, 2.0);
+
+        assert!(tg.shift_time(-1.0).is_err());
+        assert_eq!(tg.xmin, 0.5);
+        tg.shift_time_with(-1.0, true).unwrap();
+        assert_eq!((tg.xmin, tg.get_tier("words").unwrap().intervals[0].xmin), (-0.5, -0.5));
+        assert!(tg.shift_time(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_scale_time() {
+        let mut tg = TextGrid::new(0.0, 4.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 4.0, &[(0.0, 0.3, "a"), (0.3, 1.7, "b"), (1.7, 4.0, "c")])).unwrap();
+        tg.add_tier(point_tier("marks", 0.0, 4.0, &[(1.0, "x"), (3.5, "y")])).unwrap();
+        let before: Vec<f64> = tg.get_tier("words").unwrap().intervals.iter().map(|i| i.xmax - i.xmin).collect();
+
+        tg.scale_time(1.5).unwrap();
+        assert_eq!((tg.xmin, tg.xmax), (0.0, 6.0));
+        let words = tg.get_tier("words").unwrap();
+        assert_eq!(words.xmax, 6.0);
+        for (interval, duration) in words.intervals.iter().zip(&before) {
+            assert!((interval.xmax - interval.xmin - duration * 1.5).abs() < 1e-12);
+        }
+        let times: Vec<f64> = tg.get_tier("marks").unwrap().points.iter().map(|p| p.time).collect();
+        assert_eq!(times, [1.5, 5.25]);
+        assert!(validate_textgrid(&tg).is_ok());
+
+        tg.undo().unwrap();
+        assert_eq!(tg.xmax, 4.0);
+        assert_eq!(tg.get_tier("words").unwrap().intervals[1].xmax, 1.7);
+        assert!(tg.scale_time(0.0).is_err());
+        assert!(tg.scale_time(-2.0).is_err());
+        assert!(tg.scale_time(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_move_tier() {
+        let mut tg = TextGrid::new(0.0, 1.0).unwrap();
+        for name in ["a", "b", "c", "d"] {
+            tg.add_tier(interval_tier(name, 0.0, 1.0, &[])).unwrap();
+        }
+        let order = |tg: &TextGrid| tg.tiers.iter().map(|t| t.name.clone()).collect::<Vec<_>>().join("");
+
+        tg.move_tier(0, 3).unwrap();
+        assert_eq!(order(&tg), "bcda");
+        tg.move_tier(3, 1).unwrap();
+        assert_eq!(order(&tg), "bacd");
+        assert!(tg.move_tier(4, 0).is_err());
+        assert!(tg.move_tier(0, 4).is_err());
+
+        tg.undo().unwrap();
+        assert_eq!(order(&tg), "bcda");
+        tg.undo().unwrap();
+        assert_eq!(order(&tg), "abcd");
+        tg.redo().unwrap();
+        assert_eq!(order(&tg), "bcda");
+        tg.redo().unwrap();
+        assert_eq!(order(&tg), "bacd");
+    }
+
+    #[test]
+    fn test_redo_several_changes_in_a_row() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(interval_tier("a", 0.0, 2.0, &[])).unwrap();
+        tg.rename_tier("a", "words".to_string()).unwrap();
+        tg.tier_add_interval("words", Interval { xmin: 0.0, xmax: 1.0, text: "x".to_string(), score: None }).unwrap();
+        tg.tier_add_interval("words", Interval { xmin: 1.0, xmax: 2.0, text: "y".to_string(), score: None }).unwrap();
+
+        for _ in 0..4 {
+            tg.undo().unwrap();
+        }
+        assert!(tg.tiers.is_empty());
+        for _ in 0..4 {
+            tg.redo().unwrap();
+        }
+        let texts: Vec<&str> = tg.get_tier("words").unwrap().intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["x", "y"]);
+        assert!(tg.redo().is_err());
+
+        // A new edit after an undo still discards the undone changes.
+        tg.undo().unwrap();
+        tg.rename_tier("words", "w".to_string()).unwrap();
+        assert!(tg.redo().is_err());
+    }
+
+    #[test]
+    fn test_duplicate_tier_names_fail_validation() {
+        let tg = TextGrid::new(0.0, 2.0).unwrap().with_tiers(vec![
+            interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a")]),
+            interval_tier("words", 0.0, 2.0, &[(1.0, 2.0, "b")]),
+        ]);
+
+        match validate_textgrid(&tg) {
+            Err(TextGridError::Format(message)) => assert_eq!(message, "Duplicate tier name 'words'"),
+            other => panic!("expected a duplicate name error, got {:?}", other),
+        }
+        let options = ValidationOptions { allow_duplicate_tier_names: true, ..ValidationOptions::default() };
+        assert!(validate_textgrid_with(&tg, &options).is_ok());
+    }
+
+    #[test]
+    fn test_dedup_points_unsorted() {
+        let mut tier = point_tier("tones", 0.0, 3.0, &[(2.0, "a"), (0.5, "b"), (1.0, "c"), (2.005, "d")]);
+        assert_eq!(tier.dedup_points(0.01), 1);
+        let marks: Vec<(f64, &str)> = tier.points.iter().map(|p| (p.time, p.mark.as_str())).collect();
+        assert_eq!(marks, [(0.5, "b"), (1.0, "c"), (2.0, "a")]);
+    }
+
+    #[test]
+    fn test_merge_point_tiers_rejects_existing_name() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(point_tier("p", 0.0, 2.0, &[(0.5, "a")])).unwrap();
+        tg.add_tier(point_tier("q", 0.0, 2.0, &[(1.5, "b")])).unwrap();
+
+        assert!(tg.merge_point_tiers("p", "q", "p".to_string(), 0.0).is_err());
+        assert!(tg.merge_point_tiers("p", "q", "q".to_string(), 0.0).is_err());
+        assert_eq!(tg.tiers.len(), 2);
+        assert_eq!(tg.get_tier("p").unwrap().points.len(), 1);
+    }
+
+    #[test]
+    fn test_annotated_indices_with_multiline_tier_name() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(interval_tier("two\nlines", 0.0, 2.0, &[(0.0, 2.0, "a")])).unwrap();
+        let mut bytes = Vec::new();
+        write_textgrid_to(&tg, &mut bytes, false, &TextWriteOptions { annotate_indices: true }).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("    item [1]: ! two\n"));
+
+        let loaded = TextGrid::parse_bytes(&bytes).unwrap();
+        assert_eq!(loaded.tiers[0].name, "two\nlines");
+        assert_eq!(loaded.tiers[0].intervals[0].text, "a");
+    }
+
+    #[test]
+    fn test_tiers_mut_invalid_edit_is_detected() {
+        let mut textgrid = TextGrid::new(0.0, 3.0).unwrap();
+        textgrid.add_tier(interval_tier("words", 0.0, 3.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+
+        let mut tiers = textgrid.tiers_mut();
+        tiers[0].intervals.push(Interval { xmin: 0.5, xmax: 1.5, text: "overlap".to_string(), score: None });
+        match tiers.finish() {
+            Err(TextGridError::Format(message)) => assert!(message.contains("Overlapping"), "{}", message),
+            other => panic!("expected an overlap error, got {:?}", other),
+        }
+        assert!(validate_textgrid(&textgrid).is_err());
+
+        textgrid.undo().unwrap();
+        assert!(validate_textgrid(&textgrid).is_ok());
+        assert_eq!(textgrid.tiers()[0].intervals().len(), 2);
+    }
+
+    #[test]
+    fn test_audacity_labels_flatten_line_breaks() {
+        let tier = interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "two\nlines"), (1.0, 2.0, "crlf\r\nlabel")]);
+        let labels = tier.to_audacity_labels();
+        assert_eq!(labels, "0.000000\t1.000000\ttwo lines\n1.000000\t2.000000\tcrlf label\n");
+
+        let imported = Tier::from_audacity_labels("words", &labels).unwrap();
+        let texts: Vec<&str> = imported.intervals.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["two lines", "crlf label"]);
+
+        let points = point_tier("marks", 0.0, 2.0, &[(1.0, "a\rb")]);
+        assert_eq!(points.to_audacity_labels(), "1.000000\t1.000000\ta b\n");
+    }
+
+    #[test]
+    fn test_canonicalize_boundaries_snaps_tier_edges() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 2.0, &[(1e-10, 1.0, "a"), (1.0, 2.0 - 1e-10, "b")])).unwrap();
+        assert_eq!(tg.canonicalize_boundaries(1e-6), 2);
+        let words = tg.get_tier("words").unwrap();
+        assert!(words.is_gapless(0.0));
+    }
+
+    #[test]
+    fn test_concurrent_atomic_writes() {
+        let dir = std::env::temp_dir().join(format!("textgrid_atomic_threads_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("shared.TextGrid");
+
+        let handles: Vec<_> = (0..8)
+            .map(|n| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let mut textgrid = TextGrid::new(0.0, 2.0).unwrap();
+                    textgrid.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, &n.to_string())])).unwrap();
+                    textgrid.to_file_atomic(&path, false)
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+        let loaded = TextGrid::from_file(&path);
+        let entries = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(results.iter().all(Result::is_ok), "{:?}", results);
+        assert_eq!(loaded.unwrap().tiers[0].intervals.len(), 1);
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn test_noop_edits_are_not_recorded() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a"), (1.0, 2.0, "b")])).unwrap();
+        tg.add_tier(point_tier("marks", 0.0, 2.0, &[(0.5, "x"), (1.5, "y")])).unwrap();
+        tg.tier_apply_label_map("words", &std::collections::HashMap::from([("a".to_string(), "A".to_string())])).unwrap();
+
+        let unmapped = std::collections::HashMap::from([("zz".to_string(), "Z".to_string())]);
+        assert_eq!(tg.tier_apply_label_map("words", &unmapped).unwrap(), 0);
+        assert_eq!(tg.tier_dedup_points("marks", 0.01).unwrap(), 0);
+        assert_eq!(tg.snap_boundaries_to_points("words", "marks", 0.01).unwrap(), 0);
+
+        tg.undo().unwrap();
+        assert_eq!(tg.get_tier("words").unwrap().intervals[0].text, "a");
+    }
+
+    #[test]
+    fn test_to_frames_rejects_huge_frame_counts() {
+        let tier = interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")]);
+        assert!(matches!(tier.to_frames(1e-12), Err(TextGridError::Format(_))));
+        assert_eq!(tier.to_frames(1e-6).unwrap().len(), 1_000_000);
+    }
+
+    #[test]
+    fn test_sample_labels_rejects_huge_sample_counts() {
+        let tier = interval_tier("words", 0.0, 1.0, &[(0.0, 1.0, "a")]);
+        assert!(matches!(tier.sample_labels(1e-12), Err(TextGridError::Format(_))));
+    }
+
+    #[test]
+    fn test_duplicate_tier_names_rejected_on_creation() {
+        let mut tg = TextGrid::new(0.0, 2.0).unwrap();
+        tg.add_tier(interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a")])).unwrap();
+        tg.add_tier(interval_tier("phones", 0.0, 2.0, &[(1.0, 2.0, "b")])).unwrap();
+
+        match tg.add_tier(interval_tier("words", 0.0, 2.0, &[])) {
+            Err(TextGridError::Format(message)) => assert_eq!(message, "Tier 'words' already exists"),
+            other => panic!("expected a duplicate name error, got {:?}", other),
+        }
+        assert!(tg.rename_tier("phones", "words".to_string()).is_err());
+        assert!(tg.rename_tier("phones", "phones".to_string()).is_ok());
+        assert!(tg.merge_tiers("words", "phones", "words".to_string()).is_err());
+        assert_eq!(tg.tiers.len(), 2);
+        assert!(validate_textgrid(&tg).is_ok());
+
+        tg.set_allow_duplicate_tier_names(true);
+        tg.add_tier(interval_tier("words", 0.0, 2.0, &[])).unwrap();
+        tg.rename_tier("phones", "words".to_string()).unwrap();
+        tg.merge_tiers("words", "words", "words".to_string()).unwrap();
+        assert_eq!(tg.tiers.len(), 4);
+        assert!(validate_textgrid(&tg).is_err());
+        assert!(tg.tiers_mut().finish().is_ok());
+
+        let built = TextGridBuilder::new()
+            .tier(interval_tier("words", 0.0, 2.0, &[]))
+            .tier(interval_tier("words", 0.0, 2.0, &[]));
+        assert!(built.clone().build().is_err());
+        let mut built = built.allow_duplicate_tier_names(true).build().unwrap();
+        assert!(built.add_tier(interval_tier("words", 0.0, 2.0, &[])).is_ok());
+    }
+
+    #[test]
+    fn test_load_and_write_duplicate_tier_names() {
+        let tg = TextGrid::new(0.0, 2.0).unwrap().with_tiers(vec![
+            interval_tier("words", 0.0, 2.0, &[(0.0, 1.0, "a")]),
+            interval_tier("words", 0.0, 2.0, &[(1.0, 2.0, "b")]),
+        ]);
+        let text = tg.to_text_string(false).unwrap();
+        let path = std::env::temp_dir().join("textgrid_duplicate_names.TextGrid");
+        tg.to_file(&path, false).unwrap();
+
+        assert_eq!(TextGrid::from_file(&path).unwrap().tiers.len(), 2);
+        assert_eq!(TextGrid::parse_bytes(text.as_bytes()).unwrap().tiers.len(), 2);
+        assert_eq!(text.parse::<TextGrid>().unwrap().tiers.len(), 2);
+        let strict = ValidationOptions::default();
+        assert!(TextGrid::from_file_with(&path, &strict).is_err());
+        assert!(TextGrid::parse_bytes_with(text.as_bytes(), &strict).is_err());
+
+        TextGrid::append_tier_to_file(&path, interval_tier("phones", 0.0, 2.0, &[])).unwrap();
+        assert_eq!(TextGrid::from_file(&path).unwrap().tiers.len(), 3);
+        assert!(TextGrid::append_tier_to_file(&path, interval_tier("words", 0.0, 2.0, &[])).is_err());
+
+        let (repaired, warnings) = TextGrid::from_file_lenient(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let names: Vec<&str> = repaired.tiers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["words", "words (2)", "phones"]);
+        assert_eq!(warnings, ["Tier 'words' renamed to 'words (2)' because the name is already used"]);
+    }
+}
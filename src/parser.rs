@@ -9,13 +9,13 @@
 //! - **Short Format**: Compact format with bare values (e.g., `0` instead of `xmin = 0`).
 //!
 //! ## Usage
-//! ```rust
+//! ```rust,no_run
 //! use textgrid::parse_textgrid;
 //!
 //! fn main() -> Result<(), textgrid::TextGridError> {
 //!     let textgrid = parse_textgrid("example.TextGrid")?;
 //!     println!("TextGrid bounds: {} to {}", textgrid.xmin, textgrid.xmax);
-//!     for tier in &textgrid.tiers {
+//!     for tier in textgrid.tiers() {
 //!         println!("Tier: {}", tier.name);
 //!     }
 //!     Ok(())
@@ -40,58 +40,156 @@ use std::path::Path;
 /// - `TextGridError::Format` if the file is malformed (e.g., invalid headers, missing data, or incorrect syntax).
 ///
 /// # Examples
-/// ```rust
+/// ```rust,no_run
 /// let tg = textgrid::parse_textgrid("test.TextGrid").unwrap();
-/// assert_eq!(tg.tiers.len(), 1); // Assuming test.TextGrid has one tier
+/// assert_eq!(tg.tiers().len(), 1); // Assuming test.TextGrid has one tier
 /// ```
 pub fn parse_textgrid<P: AsRef<Path>>(path: P) -> Result<TextGrid, TextGridError> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+    parse_lines(&lines, &|label| label)
+}
+
+/// Parses TextGrid text from a reader, transforming every label as it is read.
+///
+/// # Arguments
+/// * `reader` - Buffered reader over the text of a `.TextGrid` file.
+/// * `label_fn` - Function applied to every interval text and point mark.
+///
+/// # Returns
+/// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::IO` if reading fails.
+/// - `TextGridError::Format` if the content is malformed.
+pub(crate) fn parse_reader_with_label_fn<R: BufRead>(reader: R, label_fn: &dyn Fn(String) -> String) -> Result<TextGrid, TextGridError> {
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+    parse_lines(&lines, label_fn)
+}
+
+/// Parses TextGrid text content held in memory.
+///
+/// # Arguments
+/// * `content` - The full text of a `.TextGrid` file.
+///
+/// # Returns
+/// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the content is malformed.
+pub(crate) fn parse_textgrid_str(content: &str) -> Result<TextGrid, TextGridError> {
+    let lines: Vec<String> = content.lines().map(String::from).collect();
+    parse_lines(&lines, &|label| label)
+}
+
+/// Parses the lines of a TextGrid file, detecting the long or short format.
+///
+/// # Arguments
+/// * `lines` - The lines of the file, without line terminators.
+/// * `label_fn` - Function applied to every interval text and point mark.
+///
+/// # Returns
+/// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the headers are invalid or the body is malformed.
+fn parse_lines(lines: &[String], label_fn: &dyn Fn(String) -> String) -> Result<TextGrid, TextGridError> {
     let mut iter = lines.iter().peekable();
 
     let first_line = iter.next().ok_or(TextGridError::Format("Empty file".into()))?;
-    if first_line != "File type = \"ooTextFile\"" {
+    let first_line = first_line.strip_prefix('\u{feff}').unwrap_or(first_line);
+    if !is_header_line(first_line, "File type", "ooTextFile") {
         return Err(TextGridError::Format("Invalid file type".into()));
     }
 
     let second_line = iter.next().ok_or(TextGridError::Format("Missing object class".into()))?;
-    if second_line != "Object class = \"TextGrid\"" {
+    if !is_header_line(second_line, "Object class", "TextGrid") {
         return Err(TextGridError::Format("Invalid object class".into()));
     }
 
-    let is_short_format = iter.peek().map_or(false, |line| !line.contains("xmin = "));
+    // Praat separates the headers from the body with a blank line.
+    while iter.next_if(|line| line.trim().is_empty()).is_some() {}
+    let is_short_format = iter.peek().is_some_and(|line| is_short_format_line(line));
     if is_short_format {
-        parse_short_format(&mut iter)
+        parse_short_format(&mut iter, label_fn)
     } else {
-        parse_long_format(&mut iter)
+        parse_long_format(&mut iter, label_fn)
     }
 }
 
+/// Checks a header line of the form `key = "value"`, tolerating whitespace around the `=`.
+///
+/// The key's words and the quoted value must still match exactly.
+///
+/// # Arguments
+/// * `line` - The header line to check.
+/// * `key` - The expected key, e.g. `File type`.
+/// * `value` - The expected value, without quotes.
+///
+/// # Returns
+/// Returns `true` if the line declares `key` with the given `value`.
+fn is_header_line(line: &str, key: &str, value: &str) -> bool {
+    line.split_once('=').is_some_and(|(found_key, found_value)| {
+        found_key.split_whitespace().eq(key.split_whitespace())
+            && found_value.trim().strip_prefix('"').and_then(|v| v.strip_suffix('"')) == Some(value)
+    })
+}
+
+/// Detects whether a `.TextGrid` text file uses the short format.
+///
+/// # Arguments
+/// * `path` - Path to the `.TextGrid` file, implementing `AsRef<Path>`.
+///
+/// # Returns
+/// Returns `true` for the short format and `false` for the long format.
+///
+/// # Errors
+/// - `TextGridError::IO` if the file cannot be opened or read.
+pub(crate) fn detect_short_format<P: AsRef<Path>>(path: P) -> Result<bool, TextGridError> {
+    let file = File::open(path)?;
+    let mut body = BufReader::new(file).lines().skip(2);
+    let first_body_line = body.find(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty())).transpose()?;
+    Ok(first_body_line.is_some_and(|line| is_short_format_line(&line)))
+}
+
+/// Checks whether the first line after the headers belongs to the short format.
+fn is_short_format_line(line: &str) -> bool {
+    !line.contains("xmin = ")
+}
+
 /// Parses a TextGrid file in the long (verbose) format.
 ///
 /// # Arguments
 /// * `lines` - Iterator over the lines of the file, with peekable functionality.
+/// * `label_fn` - Function applied to every interval text and point mark.
 ///
 /// # Returns
 /// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
 ///
 /// # Errors
 /// - `TextGridError::Format` if the file structure is invalid or data cannot be parsed.
-fn parse_long_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>) -> Result<TextGrid, TextGridError> {
+fn parse_long_format(
+    lines: &mut std::iter::Peekable<std::slice::Iter<String>>,
+    label_fn: &dyn Fn(String) -> String,
+) -> Result<TextGrid, TextGridError> {
     let xmin = parse_value(lines.next(), "xmin = ")?;
     let xmax = parse_value(lines.next(), "xmax = ")?;
-    let tiers_exists = lines.next().ok_or(TextGridError::Format("Missing tiers flag".into()))?;
-    if !tiers_exists.contains("tiers? <exists>") {
+    // Very old files omit the "tiers? <exists>" flag and go straight to "size = N".
+    let tiers_exists = lines.peek().ok_or(TextGridError::Format("Missing tiers flag".into()))?;
+    if tiers_exists.contains("tiers? <exists>") {
+        lines.next();
+    } else if !tiers_exists.trim().starts_with("size = ") {
         return Err(TextGridError::Format("Invalid tiers declaration".into()));
     }
 
-    let size = parse_value(lines.next(), "size = ")? as usize;
+    let size = to_count(parse_value(lines.next(), "size = ")?)?;
     lines.next(); // Skip "item []:" line
 
-    let mut tiers = Vec::with_capacity(size);
-    for _ in 0..size {
-        lines.next(); // Skip "item [n]:" line
+    let mut tiers = Vec::new();
+    for found in 0..size {
+        expect_tier(lines, size, found)?;
+        lines.next(); // Skip "item [n]:" line, including any "! name" comment
         let class_line = lines.next().ok_or(TextGridError::Format("Missing class".into()))?;
         let tier_type = if class_line.contains("IntervalTier") {
             TierType::IntervalTier
@@ -101,10 +199,15 @@ fn parse_long_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>)
             return Err(TextGridError::Format("Unknown tier type".into()));
         };
 
-        let name = extract_quoted_value(lines.next(), "name = ")?;
+        let name = extract_quoted_value(lines, "name = ")?;
         let tier_xmin = parse_value(lines.next(), "xmin = ")?;
         let tier_xmax = parse_value(lines.next(), "xmax = ")?;
-        let tier_size = parse_value(lines.next(), "intervals: size = ").unwrap_or_else(|_| parse_value(lines.next(), "points: size = ").unwrap()) as usize;
+        let size_prefix = match tier_type {
+            TierType::IntervalTier => "intervals: size = ",
+            TierType::PointTier => "points: size = ",
+        };
+        expect_tier_field(lines, &name, tier_type, size_prefix)?;
+        let tier_size = to_count(parse_value(lines.next(), size_prefix)?)?;
 
         let mut intervals = Vec::new();
         let mut points = Vec::new();
@@ -112,17 +215,19 @@ fn parse_long_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>)
             TierType::IntervalTier => {
                 for _ in 0..tier_size {
                     lines.next(); // Skip "intervals [n]:" line
+                    expect_tier_field(lines, &name, tier_type, "xmin = ")?;
                     let xmin = parse_value(lines.next(), "xmin = ")?;
                     let xmax = parse_value(lines.next(), "xmax = ")?;
-                    let text = extract_quoted_value(lines.next(), "text = ")?;
-                    intervals.push(Interval { xmin, xmax, text });
+                    let text = label_fn(extract_quoted_value(lines, "text = ")?);
+                    intervals.push(Interval { xmin, xmax, text, score: None });
                 }
             }
             TierType::PointTier => {
                 for _ in 0..tier_size {
                     lines.next(); // Skip "points [n]:" line
+                    expect_tier_field(lines, &name, tier_type, "time = ")?;
                     let time = parse_value(lines.next(), "time = ")?;
-                    let mark = extract_quoted_value(lines.next(), "mark = ")?;
+                    let mark = label_fn(extract_quoted_value(lines, "mark = ")?);
                     points.push(Point { time, mark });
                 }
             }
@@ -130,40 +235,128 @@ fn parse_long_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>)
 
         tiers.push(Tier { name, tier_type, xmin: tier_xmin, xmax: tier_xmax, intervals, points });
     }
+    expect_end(lines, size)?;
 
     Ok(TextGrid::new(xmin, xmax)?.with_tiers(tiers))
 }
 
+/// Skips blank lines and checks that another declared tier follows.
+///
+/// # Arguments
+/// * `lines` - Iterator over the lines of the file, positioned before the tier.
+/// * `declared` - Number of tiers declared by the file's `size` field.
+/// * `found` - Number of tiers parsed so far.
+///
+/// # Errors
+/// - `TextGridError::Format` reporting the declared and found counts if the file ends early.
+fn expect_tier(lines: &mut std::iter::Peekable<std::slice::Iter<String>>, declared: usize, found: usize) -> Result<(), TextGridError> {
+    while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+    if lines.peek().is_none() {
+        return Err(TextGridError::Format(format!("Expected {} tiers, found {}", declared, found)));
+    }
+    Ok(())
+}
+
+/// Checks that no further tier follows the last declared tier.
+///
+/// Other trailing lines, such as blank lines, `!` comments or metadata appended by other tools, are
+/// ignored. A further tier is recognised by its `item [n]:` header (long format) or its quoted class
+/// (short format).
+///
+/// # Arguments
+/// * `lines` - Iterator over the lines of the file, positioned after the last tier.
+/// * `declared` - Number of tiers declared by the file's `size` field.
+///
+/// # Errors
+/// - `TextGridError::Format` if another tier follows the declared tiers.
+fn expect_end(lines: &mut std::iter::Peekable<std::slice::Iter<String>>, declared: usize) -> Result<(), TextGridError> {
+    let is_tier_start = |line: &&String| {
+        let line = line.trim();
+        line.starts_with("item [") || line == "\"IntervalTier\"" || line == "\"TextTier\""
+    };
+    if let Some(line) = lines.find(is_tier_start) {
+        return Err(TextGridError::Format(format!(
+            "File declares {} tiers but has more data after them: '{}'",
+            declared,
+            line.trim()
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that the next line holds the field expected for a tier's declared class.
+///
+/// This catches corrupt files whose class line disagrees with their content (e.g. an `IntervalTier`
+/// containing `time =`/`mark =` fields) before the mismatch turns into garbage data.
+///
+/// # Arguments
+/// * `lines` - Iterator over the lines of the file, positioned before the field.
+/// * `name` - Name of the tier being parsed, for error reporting.
+/// * `tier_type` - The tier type declared by the class line.
+/// * `prefix` - The field prefix the declared class requires.
+///
+/// # Errors
+/// - `TextGridError::Format` naming the tier if the next line has a different field.
+fn expect_tier_field(
+    lines: &mut std::iter::Peekable<std::slice::Iter<String>>,
+    name: &str,
+    tier_type: TierType,
+    prefix: &str,
+) -> Result<(), TextGridError> {
+    match lines.peek() {
+        Some(line) if !line.trim().starts_with(prefix) => {
+            let class = match tier_type {
+                TierType::IntervalTier => "IntervalTier",
+                TierType::PointTier => "TextTier",
+            };
+            Err(TextGridError::Format(format!(
+                "Tier '{}' is declared as {} but has '{}' where '{}' was expected",
+                name,
+                class,
+                line.trim(),
+                prefix.trim()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Parses a TextGrid file in the short (compact) format.
 ///
 /// # Arguments
 /// * `lines` - Iterator over the lines of the file, with peekable functionality.
+/// * `label_fn` - Function applied to every interval text and point mark.
 ///
 /// # Returns
 /// Returns a `Result` containing the parsed `TextGrid` or a `TextGridError`.
 ///
 /// # Errors
 /// - `TextGridError::Format` if the file structure is invalid or data cannot be parsed.
-fn parse_short_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>) -> Result<TextGrid, TextGridError> {
+fn parse_short_format(
+    lines: &mut std::iter::Peekable<std::slice::Iter<String>>,
+    label_fn: &dyn Fn(String) -> String,
+) -> Result<TextGrid, TextGridError> {
     let xmin = parse_bare_value(lines.next())?;
     let xmax = parse_bare_value(lines.next())?;
-    let size = parse_bare_value(lines.next())? as usize;
+    let size = to_count(parse_bare_value(lines.next())?)?;
 
-    let mut tiers = Vec::with_capacity(size);
-    for _ in 0..size {
+    let mut tiers = Vec::new();
+    for found in 0..size {
+        expect_tier(lines, size, found)?;
         let tier_type_str = lines.next().ok_or(TextGridError::Format("Missing tier type".into()))?;
-        let tier_type = if tier_type_str.contains("IntervalTier") {
-            TierType::IntervalTier
-        } else if tier_type_str.contains("TextTier") {
-            TierType::PointTier
-        } else {
-            return Err(TextGridError::Format("Unknown tier type".into()));
+        // Some scripts write the class bare (`IntervalTier`) rather than quoted; names and labels stay strictly quoted.
+        let class = tier_type_str.trim();
+        let class = class.strip_prefix('"').and_then(|c| c.strip_suffix('"')).unwrap_or(class);
+        let tier_type = match class {
+            "IntervalTier" => TierType::IntervalTier,
+            "TextTier" => TierType::PointTier,
+            _ => return Err(TextGridError::Format(format!("Unknown tier type '{}'", class))),
         };
 
-        let name = extract_quoted_value_short(lines.next())?;
+        let name = extract_quoted_value_short(lines)?;
         let tier_xmin = parse_bare_value(lines.next())?;
         let tier_xmax = parse_bare_value(lines.next())?;
-        let tier_size = parse_bare_value(lines.next())? as usize;
+        let tier_size = to_count(parse_bare_value(lines.next())?)?;
 
         let mut intervals = Vec::new();
         let mut points = Vec::new();
@@ -172,14 +365,14 @@ fn parse_short_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>)
                 for _ in 0..tier_size {
                     let xmin = parse_bare_value(lines.next())?;
                     let xmax = parse_bare_value(lines.next())?;
-                    let text = extract_quoted_value_short(lines.next())?;
-                    intervals.push(Interval { xmin, xmax, text });
+                    let text = label_fn(extract_quoted_value_short(lines)?);
+                    intervals.push(Interval { xmin, xmax, text, score: None });
                 }
             }
             TierType::PointTier => {
                 for _ in 0..tier_size {
                     let time = parse_bare_value(lines.next())?;
-                    let mark = extract_quoted_value_short(lines.next())?;
+                    let mark = label_fn(extract_quoted_value_short(lines)?);
                     points.push(Point { time, mark });
                 }
             }
@@ -187,6 +380,7 @@ fn parse_short_format(lines: &mut std::iter::Peekable<std::slice::Iter<String>>)
 
         tiers.push(Tier { name, tier_type, xmin: tier_xmin, xmax: tier_xmax, intervals, points });
     }
+    expect_end(lines, size)?;
 
     Ok(TextGrid::new(xmin, xmax)?.with_tiers(tiers))
 }
@@ -211,6 +405,19 @@ fn parse_value(line: Option<&String>, prefix: &str) -> Result<f64, TextGridError
         .map_err(|e| TextGridError::Format(format!("Failed to parse number: {}", e)))
 }
 
+/// Converts a parsed count (e.g. `size = 2` or `size = 2.0`) to an integer.
+///
+/// Praat and older tools sometimes write counts as floats, so any finite whole number is accepted.
+///
+/// # Errors
+/// - `TextGridError::Format` if the value is negative, fractional, or not finite.
+fn to_count(value: f64) -> Result<usize, TextGridError> {
+    if value < 0.0 || value.fract() != 0.0 || !value.is_finite() {
+        return Err(TextGridError::Format(format!("Invalid count: {}", value)));
+    }
+    Ok(value as usize)
+}
+
 /// Parses a bare numeric value from a line (e.g., "0").
 ///
 /// # Arguments
@@ -230,8 +437,11 @@ fn parse_bare_value(line: Option<&String>) -> Result<f64, TextGridError> {
 
 /// Extracts a quoted string value from a line with a given prefix (e.g., `text = "hello"`).
 ///
+/// Any amount of whitespace may separate the `=` from the opening quote. See [`read_quoted`] for how
+/// embedded quotes and line breaks inside the value are handled.
+///
 /// # Arguments
-/// * `line` - Optional line to parse.
+/// * `lines` - Iterator over the lines of the file, positioned before the line to parse.
 /// * `prefix` - Expected prefix before the quoted value.
 ///
 /// # Returns
@@ -239,34 +449,70 @@ fn parse_bare_value(line: Option<&String>) -> Result<f64, TextGridError> {
 ///
 /// # Errors
 /// - `TextGridError::Format` if the line is missing, lacks the prefix, or the value is not quoted.
-fn extract_quoted_value(line: Option<&String>, prefix: &str) -> Result<String, TextGridError> {
-    let line = line.ok_or(TextGridError::Format("Unexpected end of file".into()))?;
-    let stripped = line.trim()
-        .strip_prefix(prefix)
-        .ok_or_else(|| TextGridError::Format(format!("Expected prefix '{}' in '{}'", prefix, line)))?;
-    if stripped.starts_with('"') && stripped.ends_with('"') {
-        Ok(stripped[1..stripped.len() - 1].to_string())
-    } else {
-        Err(TextGridError::Format("Expected quoted string".into()))
-    }
+fn extract_quoted_value(lines: &mut std::iter::Peekable<std::slice::Iter<String>>, prefix: &str) -> Result<String, TextGridError> {
+    let line = lines.next().ok_or(TextGridError::Format("Unexpected end of file".into()))?;
+    let rest = line
+        .trim_start()
+        .strip_prefix(prefix.trim_end())
+        .ok_or_else(|| TextGridError::Format(format!("Expected prefix '{}' in '{}'", prefix, line)))?
+        .trim_start()
+        .strip_prefix('"')
+        .ok_or_else(|| TextGridError::Format("Expected quoted string".into()))?;
+    read_quoted(lines, rest)
 }
 
 /// Extracts a quoted string value from a bare line (e.g., `"hello"`).
 ///
 /// # Arguments
-/// * `line` - Optional line to parse.
+/// * `lines` - Iterator over the lines of the file, positioned before the line to parse.
 ///
 /// # Returns
 /// Returns a `Result` containing the extracted `String` or a `TextGridError`.
 ///
 /// # Errors
 /// - `TextGridError::Format` if the line is missing or the value is not quoted.
-fn extract_quoted_value_short(line: Option<&String>) -> Result<String, TextGridError> {
-    let line = line.ok_or(TextGridError::Format("Unexpected end of file".into()))?;
-    let trimmed = line.trim();
-    if trimmed.starts_with('"') && trimmed.ends_with('"') {
-        Ok(trimmed[1..trimmed.len() - 1].to_string())
-    } else {
-        Err(TextGridError::Format("Expected quoted string".into()))
+fn extract_quoted_value_short(lines: &mut std::iter::Peekable<std::slice::Iter<String>>) -> Result<String, TextGridError> {
+    let line = lines.next().ok_or(TextGridError::Format("Unexpected end of file".into()))?;
+    let rest = line
+        .trim_start()
+        .strip_prefix('"')
+        .ok_or_else(|| TextGridError::Format("Expected quoted string".into()))?;
+    read_quoted(lines, rest)
+}
+
+/// Reads the remainder of a quoted value, starting just after its opening quote.
+///
+/// Praat escapes an embedded quote by doubling it (`""`) and writes line breaks literally, so the
+/// value ends at the first undoubled quote followed only by whitespace on its line, and continues
+/// onto following lines until such a quote is found. For files written by older tools, an undoubled
+/// quote with more text after it on the same line is kept as a literal quote.
+///
+/// # Arguments
+/// * `lines` - Iterator over the lines of the file, positioned after the value's first line.
+/// * `rest` - The text following the opening quote on the value's first line.
+///
+/// # Returns
+/// Returns a `Result` containing the unescaped value or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the file ends before the closing quote.
+fn read_quoted(lines: &mut std::iter::Peekable<std::slice::Iter<String>>, rest: &str) -> Result<String, TextGridError> {
+    let mut value = String::new();
+    let mut line = rest;
+    loop {
+        let mut chars = line.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            if c != '"' {
+                value.push(c);
+            } else if chars.next_if(|&(_, next)| next == '"').is_some() {
+                value.push('"');
+            } else if line[index + 1..].trim().is_empty() {
+                return Ok(value);
+            } else {
+                value.push('"');
+            }
+        }
+        line = lines.next().ok_or(TextGridError::Format("Unterminated quoted string".into()))?;
+        value.push('\n');
     }
-}
\ No newline at end of file
+}
@@ -4,7 +4,8 @@
 //! including support for tiers (IntervalTiers and PointTiers), intervals, points, and a history
 //! mechanism for undo/redo operations.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Deref, DerefMut};
 use thiserror::Error;
 
 // === Error Handling ===
@@ -39,6 +40,7 @@ impl From<std::string::FromUtf8Error> for TextGridError {
 
 /// Type of a tier, either interval-based or point-based.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TierType {
     /// A tier containing time intervals with text annotations.
     IntervalTier,
@@ -46,8 +48,80 @@ pub enum TierType {
     PointTier,
 }
 
+/// Strategy for closing gaps and overlaps between consecutive intervals.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ContiguityStrategy {
+    /// Moves each interval's end to the start of the following interval.
+    SnapToNext,
+    /// Moves each interval's start to the end of the preceding interval.
+    SnapToPrev,
+}
+
+/// Neighbor that absorbs an interval removed for being too short.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CollapseTarget {
+    /// Merges the short interval into the preceding interval (or the following one, for the first interval).
+    Previous,
+    /// Merges the short interval into the following interval (or the preceding one, for the last interval).
+    Next,
+}
+
+/// Duration used as the denominator when computing a label rate.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RateDenominator {
+    /// Total duration of the non-empty intervals.
+    LabeledDuration,
+    /// Full duration of the tier, from its `xmin` to its `xmax`.
+    TierDuration,
+}
+
+/// How blank labels affect label sequences, as in [`Tier::transition_counts_with`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum BlankHandling {
+    /// Blank labels are skipped, so the labels on either side of them still form a transition.
+    #[default]
+    Skip,
+    /// Blank labels end the sequence, so no transition spans them.
+    BreakSequence,
+}
+
+/// Decides which labels count as empty, i.e. silence rather than speech.
+///
+/// Projects differ on how they mark silence (`""`, `"sil"`, `"sp"`, `"<p:>"`, ...); methods taking an
+/// `EmptyPolicy` use it in place of the default whitespace check.
+///
+/// The `_with` variants of the speech and labeled-time methods take a policy: `speech_extent`,
+/// `trim_to_speech`, `labeled_duration`, `labeled_duration_before`, `transcript`, `invert_tier`,
+/// `labeled_region_jaccard` and `pairwise_labeled_overlap`. Other methods that skip empty labels,
+/// such as `longest_interval`, `shortest_interval`, `to_midpoint_points`, `label_duration_stats`,
+/// `transition_counts` and `tier_reports`, always use the whitespace check.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum EmptyPolicy {
+    /// Only blank or whitespace-only labels are empty.
+    #[default]
+    Blank,
+    /// Blank labels and labels equal (after trimming) to one of these tokens are empty.
+    Tokens(HashSet<String>),
+}
+
+impl EmptyPolicy {
+    /// Returns a policy treating blank labels and the common silence tokens `sil`, `sp` and `<p:>` as empty.
+    pub fn common_silence() -> Self {
+        EmptyPolicy::Tokens(["sil", "sp", "<p:>"].into_iter().map(String::from).collect())
+    }
+
+    /// Returns `true` if `label` counts as empty under this policy.
+    pub fn treats_as_empty(&self, label: &str) -> bool {
+        match self {
+            EmptyPolicy::Blank => is_blank(label),
+            EmptyPolicy::Tokens(tokens) => is_blank(label) || tokens.contains(label.trim()),
+        }
+    }
+}
+
 /// Represents a time interval with associated text.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval {
     /// Start time of the interval.
     pub xmin: f64,
@@ -55,10 +129,16 @@ pub struct Interval {
     pub xmax: f64,
     /// Text annotation for the interval.
     pub text: String,
+    /// Optional confidence score, e.g. from a forced aligner.
+    ///
+    /// Not stored in Praat text or binary files; kept by serde and CSV exports.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub score: Option<f64>,
 }
 
 /// Represents a single time point with a mark.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// Time of the point.
     pub time: f64,
@@ -67,7 +147,12 @@ pub struct Point {
 }
 
 /// Represents a tier in a TextGrid, containing intervals or points.
-#[derive(Debug, Clone)]
+///
+/// The intervals and points are only reachable through methods, so they stay sorted and every
+/// edit goes through the checks of the method making it. Build a tier with [`Tier::new`] and
+/// [`Tier::with_intervals`] or [`Tier::with_points`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tier {
     /// Name of the tier.
     pub name: String,
@@ -78,9 +163,53 @@ pub struct Tier {
     /// End time of the tier.
     pub xmax: f64,
     /// List of intervals (for IntervalTier).
-    pub intervals: Vec<Interval>,
+    pub(crate) intervals: Vec<Interval>,
     /// List of points (for PointTier).
-    pub points: Vec<Point>,
+    pub(crate) points: Vec<Point>,
+}
+
+/// Summary statistics for a single tier, as produced by [`TextGrid::tier_reports`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TierReport {
+    /// Name of the tier.
+    pub name: String,
+    /// Type of the tier.
+    pub tier_type: TierType,
+    /// Number of intervals or points.
+    pub item_count: usize,
+    /// Number of intervals or points with a non-blank label.
+    pub labeled_count: usize,
+    /// Total duration of labeled intervals in seconds (always `0.0` for PointTiers).
+    pub labeled_duration: f64,
+    /// Labeled duration as a fraction of the tier's duration (always `0.0` for PointTiers).
+    pub coverage: f64,
+    /// Number of stretches of the tier not covered by any interval (always `0` for PointTiers).
+    pub gap_count: usize,
+}
+
+/// Snapshot of every time value in a TextGrid, without labels.
+///
+/// Created by [`TextGrid::snapshot_times`] and reapplied by [`TextGrid::restore_times`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSnapshot {
+    /// Start and end time of the TextGrid.
+    bounds: (f64, f64),
+    /// Time values of each tier, in tier order.
+    tiers: Vec<TierTimes>,
+}
+
+/// Time values of a single tier within a `TimeSnapshot`.
+#[derive(Debug, Clone, PartialEq)]
+struct TierTimes {
+    /// Type of the tier the times were taken from.
+    tier_type: TierType,
+    /// Start and end time of the tier.
+    bounds: (f64, f64),
+    /// Start and end time of each interval.
+    intervals: Vec<(f64, f64)>,
+    /// Time of each point.
+    points: Vec<f64>,
 }
 
 /// Represents a change operation for undo/redo.
@@ -95,26 +224,159 @@ enum Change {
     SplitInterval(String, usize, Interval, Interval),
     MergeIntervals(String, Vec<Interval>, Vec<Interval>),
     RenameTier(String, String),
+    MoveTier(usize, usize),
     MergeTiers(String, String, String, Tier),
     AdjustBounds(f64, f64),
     InsertSilence(String, Vec<Interval>, Vec<Interval>),
+    ReplaceIntervals(String, Vec<Interval>, Vec<Interval>),
+    ReplacePoints(String, Vec<Point>, Vec<Point>),
+    ReplaceTier(usize, Tier, Tier),
+    ReplaceGrid(f64, f64, Vec<Tier>),
 }
 
+/// Default maximum number of changes stored in history.
+const DEFAULT_MAX_HISTORY: usize = 100;
+
 /// Main structure representing a Praat TextGrid with tiers and history.
+///
+/// With the `serde` feature, only the bounds and tiers are serialized; the undo/redo history
+/// is skipped and starts empty after deserialization.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextGrid {
     /// Start time of the entire TextGrid.
     pub xmin: f64,
     /// End time of the entire TextGrid.
     pub xmax: f64,
-    /// List of tiers in the TextGrid.
-    pub tiers: Vec<Tier>,
+    /// List of tiers in the TextGrid, read with [`TextGrid::tiers`] and edited with [`TextGrid::tiers_mut`].
+    pub(crate) tiers: Vec<Tier>,
     /// History of changes for undo operations.
+    #[cfg_attr(feature = "serde", serde(skip))]
     history: VecDeque<Change>,
     /// Stack of undone changes for redo operations.
+    #[cfg_attr(feature = "serde", serde(skip))]
     redo_stack: VecDeque<Change>,
     /// Maximum number of changes stored in history.
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_max_history"))]
     max_history: usize,
+    /// Whether edits may create a tier whose name is already used. Set with
+    /// [`TextGrid::set_allow_duplicate_tier_names`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    allow_duplicate_tier_names: bool,
+}
+
+/// Provides the history limit for deserialized TextGrids.
+#[cfg(feature = "serde")]
+fn default_max_history() -> usize {
+    DEFAULT_MAX_HISTORY
+}
+
+/// Tolerance under which two boundary times are considered the same.
+const BOUNDARY_EPSILON: f64 = 1e-9;
+
+/// Largest number of frames or samples a tier may be resampled into.
+const MAX_RESAMPLE_COUNT: usize = 10_000_000;
+
+/// Counts the steps of `step` seconds needed to cover `span` seconds.
+///
+/// # Errors
+/// - `TextGridError::Format` if more than `MAX_RESAMPLE_COUNT` steps are needed.
+fn resample_count(span: f64, step: f64) -> Result<usize, TextGridError> {
+    let count = (span / step - BOUNDARY_EPSILON).ceil().max(0.0);
+    if count > MAX_RESAMPLE_COUNT as f64 {
+        return Err(TextGridError::Format(format!(
+            "Resampling would produce {} entries, more than the limit of {}",
+            count, MAX_RESAMPLE_COUNT
+        )));
+    }
+    Ok(count as usize)
+}
+
+/// Crops a tier to `[start, end]`, clipping intervals and dropping points outside the window.
+fn crop_tier(tier: &Tier, start: f64, end: f64) -> Tier {
+    let window = Interval { xmin: start, xmax: end, text: String::new(), score: None };
+    Tier {
+        name: tier.name.clone(),
+        tier_type: tier.tier_type,
+        xmin: start,
+        xmax: end,
+        intervals: tier
+            .intervals
+            .iter()
+            .filter(|i| i.overlaps(&window))
+            .map(|i| Interval { xmin: i.xmin.max(start), xmax: i.xmax.min(end), text: i.text.clone(), score: i.score })
+            .collect(),
+        points: tier.points.iter().filter(|p| p.time >= start && p.time <= end).cloned().collect(),
+    }
+}
+
+/// Shifts every time value of a tier (bounds, intervals and points) by `offset` seconds.
+fn shift_tier(tier: &mut Tier, offset: f64) {
+    tier.xmin += offset;
+    tier.xmax += offset;
+    for interval in &mut tier.intervals {
+        interval.xmin += offset;
+        interval.xmax += offset;
+    }
+    for point in &mut tier.points {
+        point.time += offset;
+    }
+}
+
+/// Converts a time in seconds to the nearest sample index.
+///
+/// Rounds half away from zero (`f64::round`).
+fn seconds_to_sample(time: f64, sample_rate: f64) -> Result<usize, TextGridError> {
+    if sample_rate <= 0.0 || !sample_rate.is_finite() {
+        return Err(TextGridError::Format("Sample rate must be positive".into()));
+    }
+    if time < 0.0 || time.is_nan() {
+        return Err(TextGridError::InvalidTime);
+    }
+    Ok((time * sample_rate).round() as usize)
+}
+
+/// Returns `true` if a label counts as empty (blank or whitespace only).
+fn is_blank(text: &str) -> bool {
+    text.trim().is_empty()
+}
+
+/// Collects the time covered by an IntervalTier's intervals that are not empty under `policy`, as
+/// sorted, disjoint regions.
+fn labeled_regions(tier: &Tier, policy: &EmptyPolicy) -> Vec<(f64, f64)> {
+    let mut spans: Vec<(f64, f64)> = tier
+        .intervals
+        .iter()
+        .filter(|i| !policy.treats_as_empty(&i.text) && i.xmin < i.xmax)
+        .map(|i| (i.xmin, i.xmax))
+        .collect();
+    spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut regions: Vec<(f64, f64)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match regions.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => regions.push((start, end)),
+        }
+    }
+    regions
+}
+
+/// Sums the duration shared by two lists of sorted, disjoint regions.
+fn intersection_duration(a: &[(f64, f64)], b: &[(f64, f64)]) -> f64 {
+    let (mut i, mut j, mut total) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if end > start {
+            total += end - start;
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    total
 }
 
 impl Interval {
@@ -133,13 +395,304 @@ impl Interval {
             return Err(TextGridError::Format("Split time must be within interval bounds".into()));
         }
         Ok((
-            Interval { xmin: self.xmin, xmax: time, text: self.text.clone() },
-            Interval { xmin: time, xmax: self.xmax, text: self.text.clone() },
+            Interval { xmin: self.xmin, xmax: time, text: self.text.clone(), score: self.score },
+            Interval { xmin: time, xmax: self.xmax, text: self.text.clone(), score: self.score },
         ))
     }
+
+    /// Splits an interval into two at the specified time, giving each half its own text.
+    ///
+    /// # Arguments
+    /// * `time` - The time at which to split the interval.
+    /// * `left` - Text for the interval before `time`.
+    /// * `right` - Text for the interval after `time`.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing a tuple of the two resulting intervals or a `TextGridError`.
+    ///
+    /// # Errors
+    /// Returns `TextGridError::Format` if the split time is outside the interval bounds.
+    pub fn split_with_texts(&self, time: f64, left: String, right: String) -> Result<(Interval, Interval), TextGridError> {
+        let (left_interval, right_interval) = self.split(time)?;
+        Ok((left_interval.with_text(left), right_interval.with_text(right)))
+    }
+
+    /// Checks whether this interval touches another, within a tolerance.
+    ///
+    /// # Arguments
+    /// * `other` - The interval to compare against.
+    /// * `epsilon` - Maximum distance between the touching boundaries.
+    ///
+    /// # Returns
+    /// Returns `true` if `self.xmax` is within `epsilon` of `other.xmin`, or `other.xmax` is within `epsilon` of `self.xmin`.
+    pub fn is_adjacent_to(&self, other: &Interval, epsilon: f64) -> bool {
+        (self.xmax - other.xmin).abs() <= epsilon || (other.xmax - self.xmin).abs() <= epsilon
+    }
+
+    /// Checks whether this interval overlaps another.
+    ///
+    /// Intervals that merely touch at a shared boundary do not overlap.
+    ///
+    /// # Arguments
+    /// * `other` - The interval to compare against.
+    ///
+    /// # Returns
+    /// Returns `true` if the two intervals share any time span of non-zero length.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.xmin < other.xmax && other.xmin < self.xmax
+    }
+
+    /// Returns a copy of this interval with different text.
+    ///
+    /// # Arguments
+    /// * `text` - The text for the new interval.
+    ///
+    /// # Returns
+    /// Returns a new `Interval` with the same bounds and the given text.
+    pub fn with_text(&self, text: impl Into<String>) -> Interval {
+        Interval { xmin: self.xmin, xmax: self.xmax, text: text.into(), score: self.score }
+    }
+
+    /// Returns a copy of this interval with different bounds.
+    ///
+    /// # Arguments
+    /// * `xmin` - Start time of the new interval.
+    /// * `xmax` - End time of the new interval.
+    ///
+    /// # Returns
+    /// Returns a new `Interval` with the same text and the given bounds.
+    pub fn with_bounds(&self, xmin: f64, xmax: f64) -> Interval {
+        Interval { xmin, xmax, text: self.text.clone(), score: self.score }
+    }
+
+    /// Returns a copy of this interval with a confidence score attached.
+    ///
+    /// # Arguments
+    /// * `score` - The score for the new interval.
+    ///
+    /// # Returns
+    /// Returns a new `Interval` with the same bounds and text and the given score.
+    pub fn with_score(&self, score: f64) -> Interval {
+        Interval { score: Some(score), ..self.clone() }
+    }
+
+    /// Converts the interval bounds to sample indices.
+    ///
+    /// Each bound is multiplied by `sample_rate` and rounded to the nearest sample, with halves
+    /// rounded away from zero.
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling rate in Hz.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the `(start, end)` sample indices or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `sample_rate` is not a positive finite number.
+    /// - `TextGridError::InvalidTime` if either bound is negative.
+    pub fn to_samples(&self, sample_rate: f64) -> Result<(usize, usize), TextGridError> {
+        Ok((seconds_to_sample(self.xmin, sample_rate)?, seconds_to_sample(self.xmax, sample_rate)?))
+    }
+}
+
+impl Point {
+    /// Returns a copy of this point at a different time.
+    ///
+    /// # Arguments
+    /// * `time` - The time for the new point.
+    ///
+    /// # Returns
+    /// Returns a new `Point` with the same mark at the given time.
+    pub fn with_time(&self, time: f64) -> Point {
+        Point { time, mark: self.mark.clone() }
+    }
+
+    /// Returns a copy of this point with a different mark.
+    ///
+    /// # Arguments
+    /// * `mark` - The mark for the new point.
+    ///
+    /// # Returns
+    /// Returns a new `Point` at the same time with the given mark.
+    pub fn with_mark(&self, mark: impl Into<String>) -> Point {
+        Point { time: self.time, mark: mark.into() }
+    }
+
+    /// Converts the point time to a sample index, rounding to the nearest sample (halves away from zero).
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Sampling rate in Hz.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the sample index or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `sample_rate` is not a positive finite number.
+    /// - `TextGridError::InvalidTime` if the time is negative.
+    pub fn to_sample(&self, sample_rate: f64) -> Result<usize, TextGridError> {
+        seconds_to_sample(self.time, sample_rate)
+    }
 }
 
 impl Tier {
+    /// Creates an empty tier.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the tier.
+    /// * `tier_type` - Type of the tier.
+    /// * `xmin` - Start time of the tier.
+    /// * `xmax` - End time of the tier.
+    ///
+    /// # Returns
+    /// Returns the new tier or a `TextGridError` if `xmin >= xmax`.
+    pub fn new(name: &str, tier_type: TierType, xmin: f64, xmax: f64) -> Result<Tier, TextGridError> {
+        if xmin >= xmax {
+            return Err(TextGridError::Format("Tier xmin must be less than xmax".into()));
+        }
+        Ok(Tier { name: name.to_string(), tier_type, xmin, xmax, intervals: Vec::new(), points: Vec::new() })
+    }
+
+    /// Replaces the tier's intervals, sorting them by start time.
+    ///
+    /// The intervals are not otherwise checked; validate the tier or the TextGrid it is added to.
+    ///
+    /// # Arguments
+    /// * `intervals` - The new intervals.
+    ///
+    /// # Returns
+    /// Returns the tier.
+    pub fn with_intervals(mut self, intervals: Vec<Interval>) -> Self {
+        self.intervals = intervals;
+        self.sort_intervals();
+        self
+    }
+
+    /// Replaces the tier's points, sorting them by time.
+    ///
+    /// The points are not otherwise checked; validate the tier or the TextGrid it is added to.
+    ///
+    /// # Arguments
+    /// * `points` - The new points.
+    ///
+    /// # Returns
+    /// Returns the tier.
+    pub fn with_points(mut self, points: Vec<Point>) -> Self {
+        self.points = points;
+        self.sort_points();
+        self
+    }
+
+    /// Gets read-only access to the intervals.
+    ///
+    /// # Returns
+    /// Returns a slice of the tier's intervals, in time order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Gets read-only access to the points.
+    ///
+    /// # Returns
+    /// Returns a slice of the tier's points, in time order.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Builds an IntervalTier from intervals given as sample indices.
+    ///
+    /// Each `(start_sample, end_sample, label)` triple is converted to seconds by dividing by
+    /// `sample_rate`. The intervals must already be in order; they are not sorted.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the new tier.
+    /// * `boundaries` - The intervals as `(start_sample, end_sample, label)` triples.
+    /// * `sample_rate` - Sampling rate in Hz.
+    /// * `xmin` - Start time of the tier.
+    /// * `xmax` - End time of the tier.
+    ///
+    /// # Returns
+    /// Returns the new tier or a `TextGridError` if it is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `sample_rate` is not a positive finite number, or if the resulting
+    ///   tier fails validation (unordered, overlapping, zero-length or out-of-bounds intervals).
+    pub fn from_sample_boundaries(
+        name: &str,
+        boundaries: &[(usize, usize, String)],
+        sample_rate: f64,
+        xmin: f64,
+        xmax: f64,
+    ) -> Result<Tier, TextGridError> {
+        if sample_rate <= 0.0 || !sample_rate.is_finite() {
+            return Err(TextGridError::Format("Sample rate must be positive".into()));
+        }
+        let intervals = boundaries
+            .iter()
+            .map(|(start, end, label)| Interval {
+                xmin: *start as f64 / sample_rate,
+                xmax: *end as f64 / sample_rate,
+                text: label.clone(),
+                score: None,
+            })
+            .collect();
+        let tier = Tier {
+            name: name.to_string(),
+            tier_type: TierType::IntervalTier,
+            xmin,
+            xmax,
+            intervals,
+            points: Vec::new(),
+        };
+        tier.validate()?;
+        Ok(tier)
+    }
+
+    /// Reads an Audacity label track (`start\tend\tlabel` lines) into an IntervalTier.
+    ///
+    /// The tier spans from `0` to the latest label end.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the new tier.
+    /// * `text` - The label track text.
+    ///
+    /// # Returns
+    /// Returns the new tier or a `TextGridError` if the track is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if a line is malformed, has `start > end`, the track is empty, or the
+    ///   labels overlap or have zero length.
+    pub fn from_audacity_labels(name: &str, text: &str) -> Result<Tier, TextGridError> {
+        crate::audacity::read_labels(name, text, false)
+    }
+
+    /// Reads an Audacity label track into a tier, optionally as a PointTier of point labels.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the new tier.
+    /// * `text` - The label track text.
+    /// * `as_points` - If `true`, builds a PointTier; every label must then have equal start and end times.
+    ///
+    /// # Returns
+    /// Returns the new tier or a `TextGridError` if the track is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if a line is malformed, has `start > end`, the track is empty, a point
+    ///   label spans a range, or the resulting tier fails validation.
+    pub fn from_audacity_labels_with(name: &str, text: &str, as_points: bool) -> Result<Tier, TextGridError> {
+        crate::audacity::read_labels(name, text, as_points)
+    }
+
+    /// Writes the tier as an Audacity label track.
+    ///
+    /// Intervals become `start\tend\tlabel` lines; points are written with equal start and end times.
+    /// Line breaks inside labels are written as spaces.
+    ///
+    /// # Returns
+    /// Returns the label track text.
+    pub fn to_audacity_labels(&self) -> String {
+        crate::audacity::write_labels(self)
+    }
+
     /// Adds an interval to an IntervalTier.
     ///
     /// # Arguments
@@ -240,6 +793,28 @@ impl Tier {
         Ok((left, right))
     }
 
+    /// Splits an interval at the specified time, giving each half its own text.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the interval to split.
+    /// * `time` - Time at which to split the interval.
+    /// * `left` - Text for the interval before `time`.
+    /// * `right` - Text for the interval after `time`.
+    ///
+    /// # Returns
+    /// Returns a tuple of the two new intervals or a `TextGridError` if the operation fails.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, index is invalid, or time is out of bounds.
+    pub fn split_interval_with_texts(&mut self, index: usize, time: f64, left: String, right: String) -> Result<(Interval, Interval), TextGridError> {
+        if self.tier_type != TierType::IntervalTier || index >= self.intervals.len() {
+            return Err(TextGridError::Format("Invalid split operation".into()));
+        }
+        let (left, right) = self.intervals[index].split_with_texts(time, left, right)?;
+        self.intervals.splice(index..=index, [left.clone(), right.clone()]);
+        Ok((left, right))
+    }
+
     /// Merges adjacent intervals with matching text.
     ///
     /// # Returns
@@ -270,12 +845,12 @@ impl Tier {
 
     /// Sorts intervals by their start time (`xmin`).
     fn sort_intervals(&mut self) {
-        self.intervals.sort_by(|a, b| a.xmin.partial_cmp(&b.xmin).unwrap());
+        self.intervals.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
     }
 
     /// Sorts points by their time.
     fn sort_points(&mut self) {
-        self.points.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+        self.points.sort_by(|a, b| a.time.total_cmp(&b.time));
     }
 
     /// Renames the tier and returns the old name.
@@ -332,50 +907,1000 @@ impl Tier {
         }
         self.intervals.iter().filter(|i| i.text.contains(text)).collect()
     }
-}
 
-impl TextGrid {
-    /// Creates a new empty TextGrid with given bounds.
-    ///
-    /// # Arguments
-    /// * `xmin` - Start time of the TextGrid.
-    /// * `xmax` - End time of the TextGrid.
+    /// Validates the tier on its own, without requiring an enclosing TextGrid.
     ///
     /// # Returns
-    /// Returns a `Result` containing the new `TextGrid` or a `TextGridError`.
+    /// Returns `Ok(())` if the tier is valid or a `TextGridError` describing the first problem found.
     ///
     /// # Errors
-    /// Returns `TextGridError::Format` if `xmin` is not less than `xmax`.
-    pub fn new(xmin: f64, xmax: f64) -> Result<Self, TextGridError> {
-        if xmin >= xmax {
-            return Err(TextGridError::Format("xmin must be less than xmax".into()));
-        }
-        Ok(TextGrid {
-            xmin,
-            xmax,
-            tiers: Vec::new(),
-            history: VecDeque::new(),
-            redo_stack: VecDeque::new(),
-            max_history: 100,
-        })
+    /// - `TextGridError::Format` if `xmin >= xmax`, intervals overlap, are unordered, or exceed the tier bounds,
+    ///   or points are unordered or out of bounds.
+    pub fn validate(&self) -> Result<(), TextGridError> {
+        crate::validator::validate_tier(self)
     }
 
-    /// Saves a change to the history stack for undo/redo functionality.
-    fn save_change(&mut self, change: Change) {
-        if self.history.len() >= self.max_history {
-            self.history.pop_front();
-        }
-        self.history.push_back(change);
-        self.redo_stack.clear();
+    /// Returns owned copies of the intervals, independent of the tier's lifetime.
+    ///
+    /// # Returns
+    /// Returns a vector of cloned intervals (empty for a PointTier).
+    pub fn intervals_cloned(&self) -> Vec<Interval> {
+        self.intervals.clone()
     }
 
-    /// Undoes the last change made to the TextGrid.
+    /// Returns owned copies of the points, independent of the tier's lifetime.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if there are no changes to undo or if the undo fails.
-    pub fn undo(&mut self) -> Result<(), TextGridError> {
-        if let Some(change) = self.history.pop_back() {
-            match change {
+    /// Returns a vector of cloned points (empty for an IntervalTier).
+    pub fn points_cloned(&self) -> Vec<Point> {
+        self.points.clone()
+    }
+
+    /// Computes the extent of the labeled material in an IntervalTier.
+    ///
+    /// # Returns
+    /// Returns `Some((start, end))` spanning the `xmin` of the first non-empty interval to the `xmax`
+    /// of the last non-empty interval, or `None` if the tier is not an IntervalTier or all intervals are empty.
+    pub fn speech_extent(&self) -> Option<(f64, f64)> {
+        self.speech_extent_with(&EmptyPolicy::Blank)
+    }
+
+    /// Computes the extent of the labeled material in an IntervalTier under an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns `Some((start, end))` spanning the first to the last non-empty interval, or `None` if the
+    /// tier is not an IntervalTier or all intervals are empty under `policy`.
+    pub fn speech_extent_with(&self, policy: &EmptyPolicy) -> Option<(f64, f64)> {
+        if self.tier_type != TierType::IntervalTier {
+            return None;
+        }
+        let first = self.intervals.iter().find(|i| !policy.treats_as_empty(&i.text))?;
+        let last = self.intervals.iter().rev().find(|i| !policy.treats_as_empty(&i.text))?;
+        Some((first.xmin, last.xmax))
+    }
+
+    /// Sums the durations of the non-empty intervals.
+    ///
+    /// # Returns
+    /// Returns the labeled duration in seconds.
+    pub fn labeled_duration(&self) -> f64 {
+        self.labeled_duration_with(&EmptyPolicy::Blank)
+    }
+
+    /// Sums the durations of the intervals that are not empty under an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns the labeled duration in seconds.
+    pub fn labeled_duration_with(&self, policy: &EmptyPolicy) -> f64 {
+        self.intervals
+            .iter()
+            .filter(|i| !policy.treats_as_empty(&i.text))
+            .map(|i| i.xmax - i.xmin)
+            .sum()
+    }
+
+    /// Sums the labeled duration that lies before `time`.
+    ///
+    /// Non-empty intervals ending at or before `time` count in full; an interval straddling `time`
+    /// contributes only its part before `time`.
+    ///
+    /// # Arguments
+    /// * `time` - The cut-off time in seconds.
+    ///
+    /// # Returns
+    /// Returns the labeled duration before `time`, in seconds.
+    pub fn labeled_duration_before(&self, time: f64) -> f64 {
+        self.labeled_duration_before_with(time, &EmptyPolicy::Blank)
+    }
+
+    /// Sums the labeled duration that lies before `time`, with empty intervals decided by an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `time` - The cut-off time in seconds.
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns the labeled duration before `time`, in seconds.
+    pub fn labeled_duration_before_with(&self, time: f64, policy: &EmptyPolicy) -> f64 {
+        self.intervals
+            .iter()
+            .filter(|i| !policy.treats_as_empty(&i.text) && i.xmin < time)
+            .map(|i| i.xmax.min(time) - i.xmin)
+            .sum()
+    }
+
+    /// Builds a PointTier with a point at the midpoint of each non-empty interval.
+    ///
+    /// Each point carries its interval's text. The new tier keeps this tier's name and bounds.
+    ///
+    /// # Returns
+    /// Returns the new PointTier, or a `TextGridError` if this tier is not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is a PointTier.
+    pub fn to_midpoint_points(&self) -> Result<Tier, TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only take midpoints of an IntervalTier".into()));
+        }
+        let points = self
+            .intervals
+            .iter()
+            .filter(|i| !is_blank(&i.text))
+            .map(|i| Point { time: (i.xmin + i.xmax) / 2.0, mark: i.text.clone() })
+            .collect();
+        Ok(Tier {
+            name: self.name.clone(),
+            tier_type: TierType::PointTier,
+            xmin: self.xmin,
+            xmax: self.xmax,
+            intervals: Vec::new(),
+            points,
+        })
+    }
+
+    /// Finds the longest non-empty interval, preferring the earliest on a tie.
+    ///
+    /// # Returns
+    /// Returns the interval, or `None` if the tier has no non-empty intervals.
+    pub fn longest_interval(&self) -> Option<&Interval> {
+        self.intervals
+            .iter()
+            .filter(|i| !is_blank(&i.text))
+            .reduce(|best, i| if i.xmax - i.xmin > best.xmax - best.xmin { i } else { best })
+    }
+
+    /// Finds the shortest non-empty interval, preferring the earliest on a tie.
+    ///
+    /// # Returns
+    /// Returns the interval, or `None` if the tier has no non-empty intervals.
+    pub fn shortest_interval(&self) -> Option<&Interval> {
+        self.intervals
+            .iter()
+            .filter(|i| !is_blank(&i.text))
+            .reduce(|best, i| if i.xmax - i.xmin < best.xmax - best.xmin { i } else { best })
+    }
+
+    /// Snaps interval boundaries that differ by at most `epsilon` so they become identical.
+    ///
+    /// Each interval's `xmin` is set to the previous interval's `xmax`, the first `xmin` to the
+    /// tier's `xmin`, and the last `xmax` to the tier's `xmax`, whenever they are within `epsilon`.
+    ///
+    /// # Returns
+    /// Returns the number of boundaries that were moved.
+    pub(crate) fn snap_boundaries(&mut self, epsilon: f64) -> usize {
+        let mut snapped = 0;
+        let mut prev_xmax = self.xmin;
+        for interval in &mut self.intervals {
+            if interval.xmin != prev_xmax && (interval.xmin - prev_xmax).abs() <= epsilon {
+                interval.xmin = prev_xmax;
+                snapped += 1;
+            }
+            prev_xmax = interval.xmax;
+        }
+        if let Some(last) = self.intervals.last_mut()
+            && last.xmax != self.xmax
+            && (last.xmax - self.xmax).abs() <= epsilon
+        {
+            last.xmax = self.xmax;
+            snapped += 1;
+        }
+        snapped
+    }
+
+    /// Makes consecutive intervals touch, removing small gaps and overlaps left by manual edits.
+    ///
+    /// Intervals are sorted first. Only the boundaries between intervals are changed; the first
+    /// interval's start and the last interval's end stay where they are.
+    ///
+    /// # Arguments
+    /// * `strategy` - Which side of each shared boundary moves.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` on failure, in which case the tier is unchanged.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, or if snapping would give an interval `xmin >= xmax`.
+    pub fn make_contiguous(&mut self, strategy: ContiguityStrategy) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only make IntervalTiers contiguous".into()));
+        }
+        let mut intervals = self.intervals.clone();
+        intervals.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
+        for i in 1..intervals.len() {
+            match strategy {
+                ContiguityStrategy::SnapToNext => intervals[i - 1].xmax = intervals[i].xmin,
+                ContiguityStrategy::SnapToPrev => intervals[i].xmin = intervals[i - 1].xmax,
+            }
+        }
+        if intervals.iter().any(|i| i.xmin >= i.xmax) {
+            return Err(TextGridError::Format("Snapping would produce an interval with xmin >= xmax".into()));
+        }
+        self.intervals = intervals;
+        Ok(())
+    }
+
+    /// Inserts an interval, trimming or splitting any existing intervals it overlaps.
+    ///
+    /// Existing intervals keep the parts that fall outside the new interval, so an interval
+    /// covering the new one on both sides is split in two around it.
+    ///
+    /// # Arguments
+    /// * `interval` - The interval to insert.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the interval cannot be inserted.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, or the interval is empty, inverted,
+    ///   or outside the tier bounds.
+    pub fn insert_interval_splitting(&mut self, interval: Interval) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only insert intervals in an IntervalTier".into()));
+        }
+        if interval.xmin >= interval.xmax || interval.xmin < self.xmin || interval.xmax > self.xmax {
+            return Err(TextGridError::Format("Interval must be non-empty and within tier bounds".into()));
+        }
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 2);
+        for existing in self.intervals.drain(..) {
+            if !existing.overlaps(&interval) {
+                intervals.push(existing);
+                continue;
+            }
+            if existing.xmin < interval.xmin {
+                intervals.push(existing.with_bounds(existing.xmin, interval.xmin));
+            }
+            if existing.xmax > interval.xmax {
+                intervals.push(existing.with_bounds(interval.xmax, existing.xmax));
+            }
+        }
+        intervals.push(interval);
+        self.intervals = intervals;
+        self.sort_intervals();
+        Ok(())
+    }
+
+    /// Stretches the first and last intervals to the tier's bounds.
+    ///
+    /// The first interval's `xmin` moves down to the tier's `xmin` and the last interval's `xmax` up to
+    /// the tier's `xmax`; no empty intervals are inserted. A tier without intervals is left unchanged.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier cannot be extended.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier or has gaps between its intervals.
+    pub fn extend_to_bounds(&mut self) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only extend intervals in an IntervalTier".into()));
+        }
+        if let Some(pair) = self.intervals.windows(2).find(|pair| pair[1].xmin - pair[0].xmax > BOUNDARY_EPSILON) {
+            return Err(TextGridError::Format(format!(
+                "Tier '{}' has an internal gap between {} and {}",
+                self.name, pair[0].xmax, pair[1].xmin
+            )));
+        }
+        if let Some(first) = self.intervals.first_mut() {
+            first.xmin = self.xmin;
+        }
+        if let Some(last) = self.intervals.last_mut() {
+            last.xmax = self.xmax;
+        }
+        Ok(())
+    }
+
+    /// Removes intervals shorter than a minimum duration, merging each into a neighbor.
+    ///
+    /// The absorbing neighbor keeps its text and is extended over the removed interval, so no
+    /// gap is left behind. A tier with a single interval is left unchanged.
+    ///
+    /// # Arguments
+    /// * `min_duration` - Intervals strictly shorter than this are collapsed.
+    /// * `into` - Which neighbor absorbs a short interval.
+    ///
+    /// # Returns
+    /// Returns the number of intervals removed, or a `TextGridError` if the tier is not an IntervalTier.
+    pub fn collapse_short_intervals(&mut self, min_duration: f64, into: CollapseTarget) -> Result<usize, TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only collapse intervals in an IntervalTier".into()));
+        }
+        let mut removed = 0;
+        let mut i = 0;
+        while i < self.intervals.len() {
+            let last = self.intervals.len() - 1;
+            if last == 0 || self.intervals[i].xmax - self.intervals[i].xmin >= min_duration {
+                i += 1;
+                continue;
+            }
+            let target = match into {
+                CollapseTarget::Previous if i > 0 => i - 1,
+                CollapseTarget::Next if i == last => i - 1,
+                _ => i,
+            };
+            let short = self.intervals.remove(i);
+            let neighbor = &mut self.intervals[target];
+            neighbor.xmin = neighbor.xmin.min(short.xmin);
+            neighbor.xmax = neighbor.xmax.max(short.xmax);
+            removed += 1;
+            i = target;
+        }
+        Ok(removed)
+    }
+
+    /// Gets the labels on either side of a shared interval boundary.
+    ///
+    /// # Arguments
+    /// * `time` - The boundary time to look up.
+    /// * `epsilon` - Tolerance for matching `time` against the boundary.
+    ///
+    /// # Returns
+    /// Returns `Some((left_label, right_label))` for the interval ending at `time` and the one starting there,
+    /// or `None` if `time` is not a boundary shared by two consecutive intervals.
+    pub fn labels_at_boundary(&self, time: f64, epsilon: f64) -> Option<(String, String)> {
+        if self.tier_type != TierType::IntervalTier {
+            return None;
+        }
+        self.intervals
+            .windows(2)
+            .find(|pair| (pair[0].xmax - time).abs() <= epsilon && (pair[1].xmin - time).abs() <= epsilon)
+            .map(|pair| (pair[0].text.clone(), pair[1].text.clone()))
+    }
+
+    /// Compares two tiers, allowing time values to differ by up to `epsilon`.
+    ///
+    /// # Arguments
+    /// * `other` - The tier to compare against.
+    /// * `epsilon` - Maximum allowed difference between corresponding time values.
+    ///
+    /// # Returns
+    /// Returns `true` if names, types, labels, and item counts match exactly and all times are within `epsilon`.
+    pub fn approx_eq(&self, other: &Tier, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= epsilon;
+        self.name == other.name
+            && self.tier_type == other.tier_type
+            && close(self.xmin, other.xmin)
+            && close(self.xmax, other.xmax)
+            && self.intervals.len() == other.intervals.len()
+            && self.points.len() == other.points.len()
+            && self
+                .intervals
+                .iter()
+                .zip(&other.intervals)
+                .all(|(a, b)| a.text == b.text && close(a.xmin, b.xmin) && close(a.xmax, b.xmax))
+            && self.points.iter().zip(&other.points).all(|(a, b)| a.mark == b.mark && close(a.time, b.time))
+    }
+
+    /// Finds the interval active at `time`, treating intervals as half-open `[xmin, xmax)`.
+    ///
+    /// Assumes intervals are sorted by `xmin`.
+    fn interval_at(&self, time: f64) -> Option<&Interval> {
+        let index = self.intervals.partition_point(|i| i.xmin <= time);
+        self.intervals[..index].last().filter(|i| time < i.xmax)
+    }
+
+    /// Checks whether an IntervalTier tiles its whole span without gaps.
+    ///
+    /// # Arguments
+    /// * `epsilon` - Tolerance, in seconds, for boundaries to count as touching.
+    ///
+    /// # Returns
+    /// Returns `true` if the first interval starts at `xmin`, the last ends at `xmax`, and every
+    /// consecutive pair touches within `epsilon`. PointTiers and tiers without intervals return `false`.
+    pub fn is_gapless(&self, epsilon: f64) -> bool {
+        if self.tier_type != TierType::IntervalTier {
+            return false;
+        }
+        let (Some(first), Some(last)) = (self.intervals.first(), self.intervals.last()) else {
+            return false;
+        };
+        (first.xmin - self.xmin).abs() <= epsilon
+            && (last.xmax - self.xmax).abs() <= epsilon
+            && self.intervals.windows(2).all(|pair| (pair[1].xmin - pair[0].xmax).abs() <= epsilon)
+    }
+
+    /// Resamples an IntervalTier into fixed-duration frames.
+    ///
+    /// Frames start at the tier's `xmin` and continue until the tier's `xmax` is covered; each frame is
+    /// labeled with the text of the interval active at the frame's center, or an empty string if none is.
+    ///
+    /// # Arguments
+    /// * `frame_duration` - Duration of each frame in seconds.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing one label per frame or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, `frame_duration` is not positive, or
+    ///   more than 10,000,000 frames would be produced.
+    pub fn to_frames(&self, frame_duration: f64) -> Result<Vec<String>, TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only convert IntervalTiers to frames".into()));
+        }
+        if frame_duration <= 0.0 || !frame_duration.is_finite() {
+            return Err(TextGridError::Format("Frame duration must be positive".into()));
+        }
+        let count = resample_count(self.xmax - self.xmin, frame_duration)?;
+        Ok((0..count)
+            .map(|frame| {
+                let center = self.xmin + (frame as f64 + 0.5) * frame_duration;
+                self.interval_at(center).map_or_else(String::new, |i| i.text.clone())
+            })
+            .collect())
+    }
+
+    /// Samples the active label of an IntervalTier at evenly spaced times.
+    ///
+    /// Sample times are `xmin`, `xmin + step`, ... up to but excluding `xmax`. Lookups are half-open,
+    /// so a sample exactly on a boundary takes the label of the interval starting there; samples in
+    /// gaps get an empty string.
+    ///
+    /// # Arguments
+    /// * `step` - Time between consecutive samples in seconds.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing `(time, label)` pairs in time order or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, `step` is not positive, or more
+    ///   than 10,000,000 samples would be produced.
+    pub fn sample_labels(&self, step: f64) -> Result<Vec<(f64, String)>, TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only sample labels from IntervalTiers".into()));
+        }
+        if step <= 0.0 || !step.is_finite() {
+            return Err(TextGridError::Format("Sampling step must be positive".into()));
+        }
+        let count = resample_count(self.xmax - self.xmin, step)?;
+        Ok((0..count)
+            .map(|k| {
+                let time = self.xmin + k as f64 * step;
+                (time, self.interval_at(time).map_or_else(String::new, |i| i.text.clone()))
+            })
+            .collect())
+    }
+
+    /// Averages the scores of the intervals that have one.
+    ///
+    /// # Returns
+    /// Returns the mean score, or `None` if no interval has a score.
+    pub fn mean_score(&self) -> Option<f64> {
+        let scores: Vec<f64> = self.intervals.iter().filter_map(|i| i.score).collect();
+        if scores.is_empty() {
+            None
+        } else {
+            Some(scores.iter().sum::<f64>() / scores.len() as f64)
+        }
+    }
+
+    /// Moves each point by a time-dependent offset.
+    ///
+    /// The tier is left unchanged if the result is invalid.
+    ///
+    /// # Arguments
+    /// * `offset` - Function returning the offset to add to a point, given its current time.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the adjusted points are invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not a PointTier, a point would leave the tier bounds,
+    ///   or two points would share the same time.
+    pub fn adjust_points<F: Fn(f64) -> f64>(&mut self, offset: F) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format("Can only adjust points in a PointTier".into()));
+        }
+        let mut points = self.points.clone();
+        for point in &mut points {
+            point.time += offset(point.time);
+        }
+        points.sort_by(|a, b| a.time.total_cmp(&b.time));
+        if points.iter().any(|p| !(self.xmin..=self.xmax).contains(&p.time)) {
+            return Err(TextGridError::Format("Adjusted point time out of tier bounds".into()));
+        }
+        if points.windows(2).any(|pair| pair[0].time >= pair[1].time) {
+            return Err(TextGridError::Format("Adjusted point times must be strictly increasing".into()));
+        }
+        self.points = points;
+        Ok(())
+    }
+
+    /// Moves a single point to a new time, keeping the points sorted.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the point to move.
+    /// * `new_time` - The point's new time.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the move is invalid; the tier is unchanged on error.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not a PointTier, the index is out of bounds, the new time
+    ///   is outside the tier bounds, or another point already has that time.
+    pub fn move_point(&mut self, index: usize, new_time: f64) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format("Can only move points in a PointTier".into()));
+        }
+        if index >= self.points.len() {
+            return Err(TextGridError::Format("Point index out of bounds".into()));
+        }
+        if !(self.xmin..=self.xmax).contains(&new_time) {
+            return Err(TextGridError::Format("Point time out of tier bounds".into()));
+        }
+        if self.points.iter().enumerate().any(|(i, p)| i != index && p.time == new_time) {
+            return Err(TextGridError::Format("Another point already exists at that time".into()));
+        }
+        self.points[index].time = new_time;
+        self.sort_points();
+        Ok(())
+    }
+
+    /// Sets an interval's bounds, moving the boundaries it shares with its neighbors along with it.
+    ///
+    /// A neighbor that touched the interval (within a tiny tolerance) has its shared boundary moved to
+    /// stay contiguous; a neighbor separated by a gap is left alone but must not end up overlapping.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the interval to resize.
+    /// * `new_xmin` - New start time of the interval.
+    /// * `new_xmax` - New end time of the interval.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the resize is invalid; the tier is unchanged on error.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, the index is out of bounds,
+    ///   `new_xmin >= new_xmax`, the new bounds leave the tier, a touching neighbor would be emptied
+    ///   or inverted, or a separate neighbor would be overlapped.
+    pub fn resize_interval(&mut self, index: usize, new_xmin: f64, new_xmax: f64) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only resize intervals in an IntervalTier".into()));
+        }
+        let (xmin, xmax) = match self.intervals.get(index) {
+            Some(interval) => (interval.xmin, interval.xmax),
+            None => return Err(TextGridError::Format("Interval index out of bounds".into())),
+        };
+        if new_xmin >= new_xmax || new_xmin.is_nan() || new_xmax.is_nan() {
+            return Err(TextGridError::Format("xmin must be less than xmax".into()));
+        }
+        if new_xmin < self.xmin || new_xmax > self.xmax {
+            return Err(TextGridError::Format("Interval out of tier bounds".into()));
+        }
+        if let Some(previous) = index.checked_sub(1).map(|i| &self.intervals[i]) {
+            let touching = (previous.xmax - xmin).abs() <= BOUNDARY_EPSILON;
+            if (touching && new_xmin <= previous.xmin) || (!touching && new_xmin < previous.xmax) {
+                return Err(TextGridError::Format("Resizing would invert or overlap the previous interval".into()));
+            }
+        }
+        if let Some(next) = self.intervals.get(index + 1) {
+            let touching = (next.xmin - xmax).abs() <= BOUNDARY_EPSILON;
+            if (touching && new_xmax >= next.xmax) || (!touching && new_xmax > next.xmin) {
+                return Err(TextGridError::Format("Resizing would invert or overlap the next interval".into()));
+            }
+        }
+
+        if let Some(previous) = index.checked_sub(1).map(|i| &mut self.intervals[i])
+            && (previous.xmax - xmin).abs() <= BOUNDARY_EPSILON
+        {
+            previous.xmax = new_xmin;
+        }
+        if let Some(next) = self.intervals.get_mut(index + 1)
+            && (next.xmin - xmax).abs() <= BOUNDARY_EPSILON
+        {
+            next.xmin = new_xmax;
+        }
+        self.intervals[index].xmin = new_xmin;
+        self.intervals[index].xmax = new_xmax;
+        Ok(())
+    }
+
+    /// Grows (or, with a negative `delta`, shrinks) every interval on both sides by `delta`.
+    ///
+    /// When growing, an interval never passes the tier bounds, and the gap between two neighbors is
+    /// split evenly so they can meet but never overlap; boundaries shared by adjacent intervals stay put.
+    ///
+    /// # Arguments
+    /// * `delta` - Amount in seconds to move each boundary outward (inward if negative).
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the padding is invalid; the tier is unchanged on error.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not an IntervalTier, `delta` is not finite, or shrinking
+    ///   would leave an interval with zero or negative duration.
+    pub fn pad_intervals(&mut self, delta: f64) -> Result<(), TextGridError> {
+        if self.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only pad intervals in an IntervalTier".into()));
+        }
+        if !delta.is_finite() {
+            return Err(TextGridError::Format("Padding must be a finite number".into()));
+        }
+        let mut intervals = self.intervals.clone();
+        intervals.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
+        let original: Vec<(f64, f64)> = intervals.iter().map(|i| (i.xmin, i.xmax)).collect();
+        for (index, interval) in intervals.iter_mut().enumerate() {
+            let (xmin, xmax) = original[index];
+            if delta >= 0.0 {
+                let room_before = match index.checked_sub(1) {
+                    Some(previous) => (xmin - original[previous].1) / 2.0,
+                    None => xmin - self.xmin,
+                };
+                let room_after = match original.get(index + 1) {
+                    Some(&(next_xmin, _)) => (next_xmin - xmax) / 2.0,
+                    None => self.xmax - xmax,
+                };
+                interval.xmin = xmin - delta.min(room_before.max(0.0));
+                interval.xmax = xmax + delta.min(room_after.max(0.0));
+            } else {
+                interval.xmin = xmin - delta;
+                interval.xmax = xmax + delta;
+                if interval.xmin >= interval.xmax {
+                    return Err(TextGridError::Format(format!(
+                        "Shrinking by {} would remove the interval at {}-{}",
+                        -delta, xmin, xmax
+                    )));
+                }
+            }
+        }
+        self.intervals = intervals;
+        Ok(())
+    }
+
+    /// Merges points whose times lie within a tolerance of an earlier kept point.
+    ///
+    /// Points are sorted by time first, so unsorted input is handled. Of each group of near-duplicate points, the first (earliest) point and its mark are kept;
+    /// the marks of the dropped points are discarded.
+    ///
+    /// # Arguments
+    /// * `tolerance` - Maximum time difference for two points to count as the same event.
+    ///
+    /// # Returns
+    /// Returns the number of points removed.
+    pub fn dedup_points(&mut self, tolerance: f64) -> usize {
+        let before = self.points.len();
+        self.sort_points();
+        self.points.dedup_by(|later, kept| later.time - kept.time <= tolerance);
+        before - self.points.len()
+    }
+
+    /// Renames every point's mark to `prefix` followed by its 1-based position in time order.
+    ///
+    /// # Arguments
+    /// * `prefix` - Text placed before each number, e.g. `p` for `p1`, `p2`, ...
+    pub fn number_points(&mut self, prefix: &str) {
+        self.sort_points();
+        for (index, point) in self.points.iter_mut().enumerate() {
+            point.mark = format!("{}{}", prefix, index + 1);
+        }
+    }
+
+    /// Joins the non-empty labels of the tier into a transcript.
+    ///
+    /// # Arguments
+    /// * `separator` - String placed between consecutive labels.
+    ///
+    /// # Returns
+    /// Returns the interval texts (or point marks, for a PointTier) in time order, skipping blank labels.
+    pub fn transcript(&self, separator: &str) -> String {
+        self.transcript_with(separator, &EmptyPolicy::Blank)
+    }
+
+    /// Joins the labels of the tier that are not empty under an `EmptyPolicy` into a transcript.
+    ///
+    /// # Arguments
+    /// * `separator` - String placed between consecutive labels.
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns the interval texts (or point marks, for a PointTier) in time order, skipping empty labels.
+    pub fn transcript_with(&self, separator: &str, policy: &EmptyPolicy) -> String {
+        let labels: Vec<&str> = match self.tier_type {
+            TierType::IntervalTier => self.intervals.iter().map(|i| i.text.as_str()).collect(),
+            TierType::PointTier => self.points.iter().map(|p| p.mark.as_str()).collect(),
+        };
+        labels.into_iter().filter(|label| !policy.treats_as_empty(label)).collect::<Vec<_>>().join(separator)
+    }
+
+    /// Counts each ordered pair of consecutive labels in the tier.
+    ///
+    /// Labels are interval texts (or point marks, for a PointTier) in time order. Blank labels are
+    /// skipped rather than breaking the sequence, so `a`, ``, `b` counts one `(a, b)` transition;
+    /// use [`Tier::transition_counts_with`] to have them break it instead.
+    ///
+    /// # Returns
+    /// Returns a map from `(previous, next)` label pairs to their counts.
+    pub fn transition_counts(&self) -> HashMap<(String, String), usize> {
+        self.transition_counts_with(BlankHandling::Skip)
+    }
+
+    /// Counts each ordered pair of consecutive labels in the tier, with blank labels handled as chosen.
+    ///
+    /// With [`BlankHandling::BreakSequence`], `a`, ``, `b` counts no transition. Only blank labels
+    /// break a sequence; a time gap between two labeled intervals does not.
+    ///
+    /// # Arguments
+    /// * `blanks` - Whether blank labels are skipped or break the sequence.
+    ///
+    /// # Returns
+    /// Returns a map from `(previous, next)` label pairs to their counts.
+    pub fn transition_counts_with(&self, blanks: BlankHandling) -> HashMap<(String, String), usize> {
+        let labels: Vec<&str> = match self.tier_type {
+            TierType::IntervalTier => self.intervals.iter().map(|i| i.text.as_str()).collect(),
+            TierType::PointTier => self.points.iter().map(|p| p.mark.as_str()).collect(),
+        };
+        let mut counts = HashMap::new();
+        let mut previous: Option<&str> = None;
+        for label in labels {
+            if is_blank(label) {
+                if blanks == BlankHandling::BreakSequence {
+                    previous = None;
+                }
+                continue;
+            }
+            if let Some(previous) = previous {
+                *counts.entry((previous.to_string(), label.to_string())).or_insert(0) += 1;
+            }
+            previous = Some(label);
+        }
+        counts
+    }
+
+    /// Sums the durations of intervals whose text is one of the given labels.
+    ///
+    /// # Arguments
+    /// * `labels` - The labels to match exactly.
+    ///
+    /// # Returns
+    /// Returns the total duration in seconds, or `0.0` if nothing matches.
+    pub fn duration_of_labels(&self, labels: &HashSet<String>) -> f64 {
+        self.intervals.iter().filter(|i| labels.contains(&i.text)).map(|i| i.xmax - i.xmin).sum()
+    }
+
+    /// Computes each label's share of the tier's total labeled duration.
+    ///
+    /// # Returns
+    /// Returns a map from each non-empty label to its total duration divided by the duration of all
+    /// labeled intervals, so the values sum to 1. Returns an empty map if no interval is labeled.
+    pub fn label_duration_distribution(&self) -> HashMap<String, f64> {
+        let mut durations: HashMap<String, f64> = HashMap::new();
+        for interval in self.intervals.iter().filter(|i| !is_blank(&i.text)) {
+            *durations.entry(interval.text.clone()).or_insert(0.0) += interval.xmax - interval.xmin;
+        }
+        let total: f64 = durations.values().sum();
+        if total > 0.0 {
+            for duration in durations.values_mut() {
+                *duration /= total;
+            }
+        } else {
+            durations.clear();
+        }
+        durations
+    }
+
+    /// Computes the count, mean and standard deviation of the durations of each label.
+    ///
+    /// The standard deviation is the population value (dividing by the count), so a label seen once
+    /// has a standard deviation of `0.0`.
+    ///
+    /// # Returns
+    /// Returns a map from each non-empty label to `(count, mean, std_dev)`, with durations in seconds.
+    pub fn label_duration_stats(&self) -> HashMap<String, (usize, f64, f64)> {
+        let mut durations: HashMap<String, Vec<f64>> = HashMap::new();
+        for interval in self.intervals.iter().filter(|i| !is_blank(&i.text)) {
+            durations.entry(interval.text.clone()).or_default().push(interval.xmax - interval.xmin);
+        }
+        durations
+            .into_iter()
+            .map(|(label, values)| {
+                let count = values.len();
+                let mean = values.iter().sum::<f64>() / count as f64;
+                let variance = values.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count as f64;
+                (label, (count, mean, variance.sqrt()))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if no interval text or point mark in the tier is non-empty.
+    fn is_empty_tier(&self) -> bool {
+        self.intervals.iter().all(|i| is_blank(&i.text)) && self.points.iter().all(|p| is_blank(&p.mark))
+    }
+
+    /// Computes the number of non-empty intervals per second, e.g. a syllable or phone rate.
+    ///
+    /// # Arguments
+    /// * `per` - Which duration to divide the count by.
+    ///
+    /// # Returns
+    /// Returns the rate in labels per second, or `0.0` if the chosen duration is zero.
+    pub fn rate(&self, per: RateDenominator) -> f64 {
+        let labeled: Vec<&Interval> = self.intervals.iter().filter(|i| !is_blank(&i.text)).collect();
+        let duration = match per {
+            RateDenominator::LabeledDuration => labeled.iter().map(|i| i.xmax - i.xmin).sum(),
+            RateDenominator::TierDuration => self.xmax - self.xmin,
+        };
+        if duration > 0.0 { labeled.len() as f64 / duration } else { 0.0 }
+    }
+
+    /// Counts empty and labeled intervals in a single pass.
+    ///
+    /// # Returns
+    /// Returns `(empty, nonempty)`: the number of intervals with blank text and with non-empty text.
+    pub fn interval_label_counts(&self) -> (usize, usize) {
+        self.intervals.iter().fold((0, 0), |(empty, nonempty), i| {
+            if is_blank(&i.text) { (empty + 1, nonempty) } else { (empty, nonempty + 1) }
+        })
+    }
+
+    /// Counts intervals with empty (blank) text.
+    ///
+    /// # Returns
+    /// Returns the number of empty intervals.
+    pub fn empty_interval_count(&self) -> usize {
+        self.interval_label_counts().0
+    }
+
+    /// Counts intervals with non-empty text.
+    ///
+    /// # Returns
+    /// Returns the number of labeled intervals.
+    pub fn nonempty_interval_count(&self) -> usize {
+        self.interval_label_counts().1
+    }
+
+    /// Counts points with an empty (blank) mark.
+    ///
+    /// # Returns
+    /// Returns the number of unmarked points.
+    pub fn empty_point_count(&self) -> usize {
+        self.points.iter().filter(|p| is_blank(&p.mark)).count()
+    }
+
+    /// Replaces each interval label with the first canonical symbol the matcher accepts.
+    ///
+    /// # Arguments
+    /// * `canonical` - The canonical symbols, in order of preference.
+    /// * `matcher` - Called as `matcher(label, symbol)`; returns `true` if `label` is a variant of `symbol`.
+    ///
+    /// # Returns
+    /// Returns the number of intervals whose text changed. Labels no symbol matches are left alone.
+    pub fn canonicalize_labels(&mut self, canonical: &[String], matcher: impl Fn(&str, &str) -> bool) -> usize {
+        let mut changed = 0;
+        for interval in &mut self.intervals {
+            if let Some(symbol) = canonical.iter().find(|symbol| matcher(&interval.text, symbol))
+                && interval.text != *symbol
+            {
+                interval.text = symbol.clone();
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Relabels intervals according to a mapping table.
+    ///
+    /// Each interval whose text exactly matches a key in `map` is replaced with the mapped value.
+    /// Intervals with unmapped labels are left untouched.
+    ///
+    /// # Arguments
+    /// * `map` - Mapping from old labels to new labels.
+    ///
+    /// # Returns
+    /// Returns the number of intervals whose text was changed.
+    pub fn apply_label_map(&mut self, map: &HashMap<String, String>) -> usize {
+        let mut changed = 0;
+        for interval in &mut self.intervals {
+            if let Some(new_text) = map.get(&interval.text) && *new_text != interval.text {
+                interval.text = new_text.clone();
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Blanks every interval whose label is not in `keep`, leaving the interval structure intact.
+    ///
+    /// # Arguments
+    /// * `keep` - The labels to keep.
+    ///
+    /// # Returns
+    /// Returns the number of intervals whose text was cleared.
+    pub fn keep_labels(&mut self, keep: &HashSet<String>) -> usize {
+        let mut cleared = 0;
+        for interval in &mut self.intervals {
+            if !interval.text.is_empty() && !keep.contains(&interval.text) {
+                interval.text.clear();
+                cleared += 1;
+            }
+        }
+        cleared
+    }
+}
+
+impl TextGrid {
+    /// Creates a new empty TextGrid with given bounds.
+    ///
+    /// # Arguments
+    /// * `xmin` - Start time of the TextGrid.
+    /// * `xmax` - End time of the TextGrid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the new `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// Returns `TextGridError::Format` if `xmin` is not less than `xmax`.
+    pub fn new(xmin: f64, xmax: f64) -> Result<Self, TextGridError> {
+        if xmin >= xmax {
+            return Err(TextGridError::Format("xmin must be less than xmax".into()));
+        }
+        Ok(TextGrid {
+            xmin,
+            xmax,
+            tiers: Vec::new(),
+            history: VecDeque::new(),
+            redo_stack: VecDeque::new(),
+            max_history: DEFAULT_MAX_HISTORY,
+            allow_duplicate_tier_names: false,
+        })
+    }
+
+    /// Sets whether edits may create a tier whose name is already used.
+    ///
+    /// By default `add_tier`, `rename_tier`, the tier merges and `build_word_tier_from_phones` reject a
+    /// name that another tier already has, matching [`crate::validate_textgrid`]. Praat itself allows
+    /// duplicates; pass `true` when they are intentional, and validate with
+    /// [`ValidationOptions::allow_duplicate_tier_names`](crate::ValidationOptions::allow_duplicate_tier_names) set.
+    ///
+    /// # Arguments
+    /// * `allow` - If `true`, duplicate tier names are accepted.
+    pub fn set_allow_duplicate_tier_names(&mut self, allow: bool) {
+        self.allow_duplicate_tier_names = allow;
+    }
+
+    /// Returns the default validation options, relaxed to accept duplicate tier names if this
+    /// TextGrid allows them.
+    pub(crate) fn validation_options(&self) -> crate::ValidationOptions {
+        crate::ValidationOptions { allow_duplicate_tier_names: self.allow_duplicate_tier_names, ..Default::default() }
+    }
+
+    /// Checks that a tier may be created or renamed to `name`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if another tier is named `name` and duplicates are not allowed.
+    fn check_new_tier_name(&self, name: &str) -> Result<(), TextGridError> {
+        if !self.allow_duplicate_tier_names && self.get_tier(name).is_some() {
+            return Err(TextGridError::Format(format!("Tier '{}' already exists", name)));
+        }
+        Ok(())
+    }
+
+    /// Saves a change to the history stack for undo/redo functionality.
+    fn save_change(&mut self, change: Change) {
+        if self.history.len() >= self.max_history {
+            self.history.pop_front();
+        }
+        self.history.push_back(change);
+        self.redo_stack.clear();
+    }
+
+    /// Replaces the bounds and tiers wholesale, returning the previous state as a `Change`.
+    fn replace_grid(&mut self, xmin: f64, xmax: f64, tiers: Vec<Tier>) -> Change {
+        let previous = Change::ReplaceGrid(self.xmin, self.xmax, std::mem::replace(&mut self.tiers, tiers));
+        self.xmin = xmin;
+        self.xmax = xmax;
+        previous
+    }
+
+    /// Undoes the last change made to the TextGrid.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if there are no changes to undo or if the undo fails.
+    pub fn undo(&mut self) -> Result<(), TextGridError> {
+        if let Some(change) = self.history.pop_back() {
+            match change {
                 Change::AddTier(tier) => {
                     let index = self.tiers.iter().position(|t| t.name == tier.name).ok_or(TextGridError::Format("Tier not found".into()))?;
                     self.tiers.remove(index);
@@ -420,449 +1945,2032 @@ impl TextGrid {
                     tier.intervals = before.clone();
                     self.redo_stack.push_back(Change::MergeIntervals(tier_name, before, after));
                 }
-                Change::RenameTier(old_name, new_name) => {
-                    let tier = self.get_tier_mut(&new_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-                    tier.name = old_name.clone();
-                    self.redo_stack.push_back(Change::RenameTier(old_name, new_name));
+                Change::RenameTier(old_name, new_name) => {
+                    let tier = self.get_tier_mut(&new_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    tier.name = old_name.clone();
+                    self.redo_stack.push_back(Change::RenameTier(old_name, new_name));
+                }
+                Change::MoveTier(from, to) => {
+                    let tier = self.tiers.remove(to);
+                    self.tiers.insert(from, tier);
+                    self.redo_stack.push_back(Change::MoveTier(from, to));
+                }
+                Change::MergeTiers(t1, t2, new_name, tier) => {
+                    let index = self.tiers.iter().position(|t| t.name == new_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    self.tiers.remove(index);
+                    self.redo_stack.push_back(Change::MergeTiers(t1, t2, new_name, tier));
+                }
+                Change::AdjustBounds(old_xmin, old_xmax) => {
+                    let new_xmin = self.xmin;
+                    let new_xmax = self.xmax;
+                    self.xmin = old_xmin;
+                    self.xmax = old_xmax;
+                    for tier in &mut self.tiers {
+                        tier.xmin = old_xmin;
+                        tier.xmax = old_xmax;
+                    }
+                    self.redo_stack.push_back(Change::AdjustBounds(new_xmin, new_xmax));
+                }
+                Change::InsertSilence(tier_name, before, _) => {
+                    let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    let after = tier.intervals.clone();
+                    tier.intervals = before.clone();
+                    self.redo_stack.push_back(Change::InsertSilence(tier_name, before, after));
+                }
+                Change::ReplaceIntervals(tier_name, before, _) => {
+                    let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    let after = tier.intervals.clone();
+                    tier.intervals = before.clone();
+                    self.redo_stack.push_back(Change::ReplaceIntervals(tier_name, before, after));
+                }
+                Change::ReplacePoints(tier_name, before, _) => {
+                    let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    let after = tier.points.clone();
+                    tier.points = before.clone();
+                    self.redo_stack.push_back(Change::ReplacePoints(tier_name, before, after));
+                }
+                Change::ReplaceTier(index, before, _) => {
+                    let tier = self.tiers.get_mut(index).ok_or(TextGridError::Format("Tier not found".into()))?;
+                    let after = std::mem::replace(tier, before.clone());
+                    self.redo_stack.push_back(Change::ReplaceTier(index, before, after));
+                }
+                Change::ReplaceGrid(xmin, xmax, tiers) => {
+                    let current = self.replace_grid(xmin, xmax, tiers);
+                    self.redo_stack.push_back(current);
+                }
+            }
+            Ok(())
+        } else {
+            Err(TextGridError::Format("No more actions to undo".into()))
+        }
+    }
+    
+    /// Redoes the last undone change.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if there are no changes to redo or if the redo fails.
+    pub fn redo(&mut self) -> Result<(), TextGridError> {
+        if let Some(change) = self.redo_stack.pop_back() {
+            // Replaying a change records it through `save_change`, which clears the redo stack;
+            // keep the remaining undone changes so consecutive redos work.
+            let pending = std::mem::take(&mut self.redo_stack);
+            let result = self.replay(change);
+            self.redo_stack = pending;
+            result
+        } else {
+            Err(TextGridError::Format("No more actions to redo".into()))
+        }
+    }
+
+    /// Reapplies an undone change, recording it in the history.
+    fn replay(&mut self, change: Change) -> Result<(), TextGridError> {
+        match change {
+            Change::AddTier(tier) => {
+                self.tiers.push(tier.clone());
+                self.save_change(Change::AddTier(tier));
+            }
+            Change::RemoveTier(index, tier) => {
+                if index < self.tiers.len() && self.tiers[index].name == tier.name {
+                    let removed = self.tiers.remove(index);
+                    self.save_change(Change::RemoveTier(index, removed));
+                } else {
+                    return Err(TextGridError::Format("Tier not found or index mismatch for redo".into()));
+                }
+            }
+            Change::AddInterval(tier_name, interval) => {
+                self.tier_add_interval(&tier_name, interval)?;
+            }
+            Change::RemoveInterval(tier_name, index, _) => {
+                self.tier_remove_interval(&tier_name, index)?;
+            }
+            Change::AddPoint(tier_name, point) => {
+                self.tier_add_point(&tier_name, point)?;
+            }
+            Change::RemovePoint(tier_name, index, _) => {
+                self.tier_remove_point(&tier_name, index)?;
+            }
+            Change::SplitInterval(tier_name, index, _, left) => {
+                self.tier_split_interval(&tier_name, index, left.xmax)?;
+            }
+            Change::MergeIntervals(tier_name, before, after) => {
+                let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                tier.intervals = after.clone();
+                self.save_change(Change::MergeIntervals(tier_name, before, after));
+            }
+            Change::RenameTier(old_name, new_name) => {
+                self.rename_tier(&old_name, new_name)?;
+            }
+            Change::MoveTier(from, to) => {
+                self.move_tier(from, to)?;
+            }
+            Change::MergeTiers(_, _, _, tier) => {
+                self.add_tier(tier)?;
+            }
+            Change::AdjustBounds(new_xmin, new_xmax) => {
+                self.adjust_bounds(new_xmin, new_xmax)?;
+            }
+            Change::InsertSilence(tier_name, before, after) => {
+                let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                tier.intervals = after.clone();
+                self.save_change(Change::InsertSilence(tier_name, before, after));
+            }
+            Change::ReplaceIntervals(tier_name, before, after) => {
+                let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                tier.intervals = after.clone();
+                self.save_change(Change::ReplaceIntervals(tier_name, before, after));
+            }
+            Change::ReplacePoints(tier_name, before, after) => {
+                let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+                tier.points = after.clone();
+                self.save_change(Change::ReplacePoints(tier_name, before, after));
+            }
+            Change::ReplaceTier(index, before, after) => {
+                let tier = self.tiers.get_mut(index).ok_or(TextGridError::Format("Tier not found".into()))?;
+                *tier = after.clone();
+                self.save_change(Change::ReplaceTier(index, before, after));
+            }
+            Change::ReplaceGrid(xmin, xmax, tiers) => {
+                let current = self.replace_grid(xmin, xmax, tiers);
+                self.save_change(current);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a tier to the TextGrid with undo support.
+    ///
+    /// # Arguments
+    /// * `tier` - The tier to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier bounds are invalid or a tier with
+    /// the same name already exists (see [`TextGrid::set_allow_duplicate_tier_names`]).
+    pub fn add_tier(&mut self, tier: Tier) -> Result<(), TextGridError> {
+        if tier.xmin < self.xmin || tier.xmax > self.xmax {
+            return Err(TextGridError::Format("Tier bounds must be within TextGrid bounds".into()));
+        }
+        self.check_new_tier_name(&tier.name)?;
+        self.save_change(Change::AddTier(tier.clone()));
+        self.tiers.push(tier);
+        Ok(())
+    }
+
+    /// Removes a tier from the TextGrid by index with undo support.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the tier to remove.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the index is out of bounds.
+    pub fn remove_tier(&mut self, index: usize) -> Result<(), TextGridError> {
+        if index >= self.tiers.len() {
+            return Err(TextGridError::Format("Tier index out of bounds".into()));
+        }
+        let tier = self.tiers.remove(index);
+        self.save_change(Change::RemoveTier(index, tier));
+        Ok(())
+    }
+
+    /// Gets a mutable reference to a tier by name.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the tier to find.
+    ///
+    /// # Returns
+    /// Returns an `Option` containing a mutable reference to the tier if found, or `None` if not.
+    pub fn get_tier_mut(&mut self, name: &str) -> Option<&mut Tier> {
+        self.tiers.iter_mut().find(|t| t.name == name)
+    }
+
+    /// Gets an immutable reference to a tier by name.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the tier to find.
+    ///
+    /// # Returns
+    /// Returns an `Option` containing a reference to the tier if found, or `None` if not.
+    pub fn get_tier(&self, name: &str) -> Option<&Tier> {
+        self.tiers.iter().find(|t| t.name == name)
+    }
+
+    /// Renames a tier with undo support.
+    ///
+    /// # Arguments
+    /// * `old_name` - Current name of the tier.
+    /// * `new_name` - New name for the tier.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or another tier is
+    /// already named `new_name` (see [`TextGrid::set_allow_duplicate_tier_names`]).
+    pub fn rename_tier(&mut self, old_name: &str, new_name: String) -> Result<(), TextGridError> {
+        if new_name != old_name {
+            self.check_new_tier_name(&new_name)?;
+        }
+        if let Some(tier) = self.get_tier_mut(old_name) {
+            let old_name = tier.rename(new_name.clone());
+            self.save_change(Change::RenameTier(old_name, new_name));
+            Ok(())
+        } else {
+            Err(TextGridError::Format("Tier not found".into()))
+        }
+    }
+
+    /// Moves a tier to a new position in the tier list, with undo support.
+    ///
+    /// The tier at `from` is removed and reinserted so that it ends up at index `to`; the tiers in
+    /// between shift by one.
+    ///
+    /// # Arguments
+    /// * `from` - Current index of the tier.
+    /// * `to` - Index the tier should have afterwards.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if either index is out of bounds.
+    pub fn move_tier(&mut self, from: usize, to: usize) -> Result<(), TextGridError> {
+        if from >= self.tiers.len() || to >= self.tiers.len() {
+            return Err(TextGridError::Format("Tier index out of bounds".into()));
+        }
+        let tier = self.tiers.remove(from);
+        self.tiers.insert(to, tier);
+        self.save_change(Change::MoveTier(from, to));
+        Ok(())
+    }
+
+    /// Merges two tiers using a custom strategy with undo support.
+    ///
+    /// # Arguments
+    /// * `name1` - Name of the first tier.
+    /// * `name2` - Name of the second tier.
+    /// * `new_name` - Name for the resulting merged tier.
+    /// * `merge_strategy` - Function to determine how overlapping intervals are merged.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tiers are not found or not IntervalTiers,
+    /// or if a tier named `new_name` already exists.
+    pub fn merge_tiers_with_strategy<F>(
+        &mut self,
+        name1: &str,
+        name2: &str,
+        new_name: String,
+        merge_strategy: F,
+    ) -> Result<(), TextGridError>
+    where
+        F: Fn(&Interval, &Interval) -> Option<Interval>,
+    {
+        let tier1 = self.get_tier(name1).ok_or(TextGridError::Format("First tier not found".into()))?.clone();
+        let tier2 = self.get_tier(name2).ok_or(TextGridError::Format("Second tier not found".into()))?.clone();
+
+        if tier1.tier_type != TierType::IntervalTier || tier2.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only merge IntervalTiers".into()));
+        }
+        self.check_new_tier_name(&new_name)?;
+
+        let mut combined_intervals = Vec::new();
+        combined_intervals.extend(tier1.intervals.clone());
+        combined_intervals.extend(tier2.intervals.clone());
+        combined_intervals.sort_by(|a, b| a.xmin.partial_cmp(&b.xmin).unwrap());
+
+        let mut new_intervals = Vec::new();
+        if !combined_intervals.is_empty() {
+            let mut current = combined_intervals[0].clone();
+            for next in combined_intervals.iter().skip(1) {
+                if current.overlaps(next) {
+                    if let Some(merged) = merge_strategy(&current, next) {
+                        current = merged;
+                    } else {
+                        new_intervals.push(current);
+                        current = next.clone();
+                    }
+                } else {
+                    new_intervals.push(current);
+                    current = next.clone();
+                }
+            }
+            new_intervals.push(current);
+        }
+
+        let new_tier = Tier {
+            name: new_name.clone(),
+            tier_type: TierType::IntervalTier,
+            xmin: self.xmin,
+            xmax: self.xmax,
+            intervals: new_intervals,
+            points: Vec::new(),
+        };
+        self.save_change(Change::MergeTiers(name1.to_string(), name2.to_string(), new_name, new_tier.clone()));
+        self.tiers.push(new_tier);
+        Ok(())
+    }
+
+    /// Merges two tiers with a default strategy (merges if text matches or one is empty).
+    ///
+    /// # Arguments
+    /// * `name1` - Name of the first tier.
+    /// * `name2` - Name of the second tier.
+    /// * `new_name` - Name for the resulting merged tier.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tiers are not found or not IntervalTiers,
+    /// or if a tier named `new_name` already exists.
+    pub fn merge_tiers(&mut self, name1: &str, name2: &str, new_name: String) -> Result<(), TextGridError> {
+        self.merge_tiers_with_strategy(name1, name2, new_name, |current, next| {
+            if current.text == next.text || current.text.is_empty() || next.text.is_empty() {
+                Some(Interval {
+                    xmin: current.xmin,
+                    xmax: current.xmax.max(next.xmax),
+                    text: if current.text.is_empty() { next.text.clone() } else { current.text.clone() },
+                    score: None,
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Merges two PointTiers into a new tier with undo support.
+    ///
+    /// The points of both tiers are combined in time order, and near-duplicates are collapsed as in
+    /// [`Tier::dedup_points`], keeping the earliest point of each group (the first tier's on a tie).
+    ///
+    /// # Arguments
+    /// * `name1` - Name of the first tier.
+    /// * `name2` - Name of the second tier.
+    /// * `new_name` - Name for the resulting merged tier.
+    /// * `dedup_tolerance` - Maximum time difference for two points to count as the same event.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tiers are not found, not PointTiers, or
+    /// `new_name` is already taken.
+    pub fn merge_point_tiers(&mut self, name1: &str, name2: &str, new_name: String, dedup_tolerance: f64) -> Result<(), TextGridError> {
+        self.check_new_tier_name(&new_name)?;
+        let tier1 = self.get_tier(name1).ok_or(TextGridError::Format("First tier not found".into()))?;
+        let tier2 = self.get_tier(name2).ok_or(TextGridError::Format("Second tier not found".into()))?;
+
+        if tier1.tier_type != TierType::PointTier || tier2.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format("Can only merge PointTiers".into()));
+        }
+
+        let mut new_tier = Tier {
+            name: new_name.clone(),
+            tier_type: TierType::PointTier,
+            xmin: self.xmin,
+            xmax: self.xmax,
+            intervals: Vec::new(),
+            points: tier1.points.iter().chain(&tier2.points).cloned().collect(),
+        };
+        new_tier.sort_points();
+        new_tier.dedup_points(dedup_tolerance);
+        self.save_change(Change::MergeTiers(name1.to_string(), name2.to_string(), new_name, new_tier.clone()));
+        self.tiers.push(new_tier);
+        Ok(())
+    }
+
+    /// Combines this TextGrid with another into a new TextGrid.
+    ///
+    /// The result spans the union of both grids' bounds. Tier order is deterministic: all of
+    /// `self`'s tiers come first in their original order, followed by all of `other`'s tiers in
+    /// their original order. Neither input is modified and the result has an empty history.
+    ///
+    /// # Arguments
+    /// * `other` - The TextGrid whose tiers are appended.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the combined `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// Returns `TextGridError::Format` if the combined bounds are invalid.
+    pub fn merge_grid(&self, other: &TextGrid) -> Result<TextGrid, TextGridError> {
+        let mut tiers = self.tiers.clone();
+        tiers.extend(other.tiers.iter().cloned());
+        Ok(TextGrid::new(self.xmin.min(other.xmin), self.xmax.max(other.xmax))?.with_tiers(tiers))
+    }
+
+    /// Combines this TextGrid with another, ordering the resulting tiers alphabetically by name.
+    ///
+    /// Behaves like [`TextGrid::merge_grid`], except that the final tiers are sorted by name.
+    /// The sort is stable, so tiers sharing a name keep their `merge_grid` relative order.
+    ///
+    /// # Arguments
+    /// * `other` - The TextGrid whose tiers are included.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the combined `TextGrid` or a `TextGridError`.
+    ///
+    /// # Errors
+    /// Returns `TextGridError::Format` if the combined bounds are invalid.
+    pub fn merge_grid_sorted(&self, other: &TextGrid) -> Result<TextGrid, TextGridError> {
+        let mut merged = self.merge_grid(other)?;
+        merged.tiers.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(merged)
+    }
+
+    /// Adjusts the bounds of the TextGrid and all tiers.
+    ///
+    /// # Arguments
+    /// * `new_xmin` - New start time.
+    /// * `new_xmax` - New end time.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the new bounds are invalid or don't encompass all data.
+    pub fn adjust_bounds(&mut self, new_xmin: f64, new_xmax: f64) -> Result<(), TextGridError> {
+        if new_xmin >= new_xmax {
+            return Err(TextGridError::Format("New xmin must be less than xmax".into()));
+        }
+        for tier in &self.tiers {
+            if !tier.intervals.is_empty() && (tier.intervals[0].xmin < new_xmin || tier.intervals.last().unwrap().xmax > new_xmax) {
+                return Err(TextGridError::Format("New bounds must encompass all tier data".into()));
+            }
+            if !tier.points.is_empty() && (tier.points[0].time < new_xmin || tier.points.last().unwrap().time > new_xmax) {
+                return Err(TextGridError::Format("New bounds must encompass all tier data".into()));
+            }
+        }
+        self.save_change(Change::AdjustBounds(self.xmin, self.xmax));
+        self.xmin = new_xmin;
+        self.xmax = new_xmax;
+        for tier in &mut self.tiers {
+            tier.xmin = new_xmin;
+            tier.xmax = new_xmax;
+        }
+        Ok(())
+    }
+
+    /// Inserts a silent interval into an IntervalTier.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier to modify.
+    /// * `start` - Start time of the silence.
+    /// * `end` - End time of the silence.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found, not an IntervalTier, or bounds are invalid.
+    pub fn insert_silence(&mut self, tier_name: &str, start: f64, end: f64) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if tier.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Can only insert silence in IntervalTier".into()));
+        }
+        if start < tier.xmin || end > tier.xmax || start >= end {
+            return Err(TextGridError::Format("Invalid silence bounds".into()));
+        }
+
+        let before = tier.intervals.clone();
+        let mut new_intervals = Vec::new();
+        for interval in &before {
+            if interval.xmax <= start || interval.xmin >= end {
+                new_intervals.push(interval.clone());
+            } else {
+                if interval.xmin < start {
+                    new_intervals.push(Interval { xmin: interval.xmin, xmax: start, text: interval.text.clone(), score: interval.score });
+                }
+                if interval.xmax > end {
+                    new_intervals.push(Interval { xmin: end, xmax: interval.xmax, text: interval.text.clone(), score: interval.score });
                 }
-                Change::MergeTiers(t1, t2, new_name, tier) => {
-                    let index = self.tiers.iter().position(|t| t.name == new_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-                    self.tiers.remove(index);
-                    self.redo_stack.push_back(Change::MergeTiers(t1, t2, new_name, tier));
+            }
+        }
+        new_intervals.push(Interval { xmin: start, xmax: end, text: "".to_string(), score: None });
+        tier.intervals = new_intervals.clone();
+        tier.sort_intervals();
+        self.save_change(Change::InsertSilence(tier_name.to_string(), before, new_intervals));
+        Ok(())
+    }
+
+    /// Computes the union of the labeled extents across all IntervalTiers.
+    ///
+    /// # Returns
+    /// Returns `Some((start, end))` covering every tier's speech extent, or `None` if no IntervalTier has labeled intervals.
+    pub fn speech_extent(&self) -> Option<(f64, f64)> {
+        self.speech_extent_with(&EmptyPolicy::Blank)
+    }
+
+    /// Computes the union of the labeled extents across all IntervalTiers under an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns `Some((start, end))` covering every tier's speech extent, or `None` if no IntervalTier has labeled intervals.
+    pub fn speech_extent_with(&self, policy: &EmptyPolicy) -> Option<(f64, f64)> {
+        self.tiers
+            .iter()
+            .filter_map(|tier| tier.speech_extent_with(policy))
+            .reduce(|(start, end), (xmin, xmax)| (start.min(xmin), end.max(xmax)))
+    }
+
+    /// Finds the longest non-empty interval across all tiers, preferring the earliest tier on a tie.
+    ///
+    /// # Returns
+    /// Returns the tier name and interval, or `None` if no tier has a non-empty interval.
+    pub fn longest_interval(&self) -> Option<(&str, &Interval)> {
+        self.tiers
+            .iter()
+            .filter_map(|tier| Some((tier.name.as_str(), tier.longest_interval()?)))
+            .reduce(|best, (name, i)| if i.xmax - i.xmin > best.1.xmax - best.1.xmin { (name, i) } else { best })
+    }
+
+    /// Finds the shortest non-empty interval across all tiers, preferring the earliest tier on a tie.
+    ///
+    /// # Returns
+    /// Returns the tier name and interval, or `None` if no tier has a non-empty interval.
+    pub fn shortest_interval(&self) -> Option<(&str, &Interval)> {
+        self.tiers
+            .iter()
+            .filter_map(|tier| Some((tier.name.as_str(), tier.shortest_interval()?)))
+            .reduce(|best, (name, i)| if i.xmax - i.xmin < best.1.xmax - best.1.xmin { (name, i) } else { best })
+    }
+
+    /// Compares two TextGrids, allowing time values to differ by up to `epsilon`.
+    ///
+    /// History is ignored; bounds and tiers (in order) are compared with [`Tier::approx_eq`].
+    ///
+    /// # Arguments
+    /// * `other` - The TextGrid to compare against.
+    /// * `epsilon` - Maximum allowed difference between corresponding time values.
+    ///
+    /// # Returns
+    /// Returns `true` if the two grids have the same structure and labels with times within `epsilon`.
+    pub fn approx_eq(&self, other: &TextGrid, epsilon: f64) -> bool {
+        (self.xmin - other.xmin).abs() <= epsilon
+            && (self.xmax - other.xmax).abs() <= epsilon
+            && self.tiers.len() == other.tiers.len()
+            && self.tiers.iter().zip(&other.tiers).all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+
+    /// Trims leading and trailing silence by rebounding the grid to its speech extent, with undo support.
+    ///
+    /// The new bounds are the union [`TextGrid::speech_extent`] across IntervalTiers. Every tier is
+    /// rebounded to match; intervals are clipped to the new bounds and dropped if they fall entirely
+    /// outside them. Points in PointTiers are kept if they lie within the new bounds (inclusive) and
+    /// dropped otherwise.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if there is no labeled material to trim to.
+    pub fn trim_to_speech(&mut self) -> Result<(), TextGridError> {
+        self.trim_to_speech_with(&EmptyPolicy::Blank)
+    }
+
+    /// Trims leading and trailing silence as in [`TextGrid::trim_to_speech`], with silence decided by an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if there is no labeled material to trim to.
+    pub fn trim_to_speech_with(&mut self, policy: &EmptyPolicy) -> Result<(), TextGridError> {
+        let (start, end) = self
+            .speech_extent_with(policy)
+            .ok_or(TextGridError::Format("No labeled intervals to trim to".into()))?;
+        let tiers = self.tiers.iter().map(|tier| crop_tier(tier, start, end)).collect();
+        let previous = self.replace_grid(start, end, tiers);
+        self.save_change(previous);
+        Ok(())
+    }
+
+    /// Extracts the annotations within a time window as a new TextGrid.
+    ///
+    /// The new grid and all its tiers span `[start, end]`. Intervals overlapping the window are kept
+    /// and truncated to it; intervals entirely outside it are dropped. Points are kept if they lie
+    /// within `[start, end]` (inclusive). Unlike [`TextGrid::adjust_bounds`], this removes and clips data.
+    ///
+    /// # Arguments
+    /// * `start` - Start of the window.
+    /// * `end` - End of the window.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the new `TextGrid`, with an empty history, or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `start >= end` or the window extends outside the grid bounds.
+    pub fn extract_region(&self, start: f64, end: f64) -> Result<TextGrid, TextGridError> {
+        if start >= end {
+            return Err(TextGridError::Format("Region start must be less than end".into()));
+        }
+        if start < self.xmin || end > self.xmax {
+            return Err(TextGridError::Format(format!(
+                "Region [{}, {}] lies outside the TextGrid bounds [{}, {}]",
+                start, end, self.xmin, self.xmax
+            )));
+        }
+        let tiers = self.tiers.iter().map(|tier| crop_tier(tier, start, end)).collect();
+        Ok(TextGrid::new(start, end)?.with_tiers(tiers))
+    }
+
+    /// Lists the tiers that have labeled content within a time range.
+    ///
+    /// An IntervalTier qualifies if any non-empty interval overlaps `[start, end)`; a PointTier
+    /// qualifies if any point lies in `[start, end)`.
+    ///
+    /// # Arguments
+    /// * `start` - Start of the range (inclusive).
+    /// * `end` - End of the range (exclusive).
+    ///
+    /// # Returns
+    /// Returns the names of the matching tiers in tier order.
+    pub fn tiers_with_content_in_range(&self, start: f64, end: f64) -> Vec<&str> {
+        let window = Interval { xmin: start, xmax: end, text: String::new(), score: None };
+        self.tiers
+            .iter()
+            .filter(|tier| match tier.tier_type {
+                TierType::IntervalTier => tier
+                    .intervals
+                    .iter()
+                    .any(|i| !is_blank(&i.text) && i.overlaps(&window)),
+                TierType::PointTier => tier.points.iter().any(|p| p.time >= start && p.time < end),
+            })
+            .map(|tier| tier.name.as_str())
+            .collect()
+    }
+
+    /// Computes the Jaccard index of the labeled time in two IntervalTiers.
+    ///
+    /// The labeled regions of each tier are the union of its non-empty intervals; the result is the
+    /// duration of their intersection divided by the duration of their union. If neither tier has any
+    /// labeled time the tiers agree perfectly and the result is `1.0`.
+    ///
+    /// # Arguments
+    /// * `tier_a` - Name of the first IntervalTier.
+    /// * `tier_b` - Name of the second IntervalTier.
+    ///
+    /// # Returns
+    /// Returns a value between `0.0` and `1.0`, or a `TextGridError` if a tier is missing or not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if either tier is not found or is not an IntervalTier.
+    pub fn labeled_region_jaccard(&self, tier_a: &str, tier_b: &str) -> Result<f64, TextGridError> {
+        self.labeled_region_jaccard_with(tier_a, tier_b, &EmptyPolicy::Blank)
+    }
+
+    /// Computes the Jaccard index of the labeled time in two IntervalTiers, with empty intervals
+    /// decided by an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `tier_a` - Name of the first IntervalTier.
+    /// * `tier_b` - Name of the second IntervalTier.
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns a value between `0.0` and `1.0`, or a `TextGridError` if a tier is missing or not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if either tier is not found or is not an IntervalTier.
+    pub fn labeled_region_jaccard_with(&self, tier_a: &str, tier_b: &str, policy: &EmptyPolicy) -> Result<f64, TextGridError> {
+        let mut regions = Vec::with_capacity(2);
+        for name in [tier_a, tier_b] {
+            let tier = self.get_tier(name).ok_or(TextGridError::Format("Tier not found".into()))?;
+            if tier.tier_type != TierType::IntervalTier {
+                return Err(TextGridError::Format(format!("Tier '{}' is not an IntervalTier", name)));
+            }
+            regions.push(labeled_regions(tier, policy));
+        }
+        let total = |regions: &[(f64, f64)]| regions.iter().map(|(start, end)| end - start).sum::<f64>();
+        let intersection = intersection_duration(&regions[0], &regions[1]);
+        let union = total(&regions[0]) + total(&regions[1]) - intersection;
+        if union <= 0.0 {
+            return Ok(1.0);
+        }
+        Ok(intersection / union)
+    }
+
+    /// Lists the IntervalTiers in tier order.
+    ///
+    /// # Returns
+    /// Returns the IntervalTiers, skipping PointTiers.
+    pub fn interval_tiers(&self) -> Vec<&Tier> {
+        self.tiers.iter().filter(|tier| tier.tier_type == TierType::IntervalTier).collect()
+    }
+
+    /// Computes the labeled-time overlap between every pair of IntervalTiers.
+    ///
+    /// Entry `[i][j]` is the duration during which both the `i`-th and `j`-th tiers of
+    /// [`TextGrid::interval_tiers`] have a non-empty interval. The matrix is symmetric, and the
+    /// diagonal holds each tier's own labeled duration.
+    ///
+    /// # Returns
+    /// Returns an N×N matrix of durations in seconds, where N is the number of IntervalTiers.
+    pub fn pairwise_labeled_overlap(&self) -> Vec<Vec<f64>> {
+        self.pairwise_labeled_overlap_with(&EmptyPolicy::Blank)
+    }
+
+    /// Computes the labeled-time overlap between every pair of IntervalTiers, with empty intervals
+    /// decided by an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns an N×N matrix of durations in seconds, as in [`TextGrid::pairwise_labeled_overlap`].
+    pub fn pairwise_labeled_overlap_with(&self, policy: &EmptyPolicy) -> Vec<Vec<f64>> {
+        let regions: Vec<Vec<(f64, f64)>> = self.interval_tiers().into_iter().map(|tier| labeled_regions(tier, policy)).collect();
+        let mut matrix = vec![vec![0.0; regions.len()]; regions.len()];
+        for i in 0..regions.len() {
+            for j in i..regions.len() {
+                let overlap = intersection_duration(&regions[i], &regions[j]);
+                matrix[i][j] = overlap;
+                matrix[j][i] = overlap;
+            }
+        }
+        matrix
+    }
+
+    /// Compares a reference and a hypothesis IntervalTier, marking where their labels agree.
+    ///
+    /// The grid's bounds are cut at every boundary of either tier, and each resulting segment is labeled
+    /// `"match"` if both tiers have the same label there and `"diff"` otherwise. Gaps and blank labels
+    /// count as the same empty label. The new tier is gapless and the TextGrid itself is left unchanged.
+    ///
+    /// # Arguments
+    /// * `ref_tier` - Name of the reference IntervalTier.
+    /// * `hyp_tier` - Name of the hypothesis IntervalTier.
+    /// * `result_name` - Name of the new tier.
+    ///
+    /// # Returns
+    /// Returns the new tier, or a `TextGridError` if either tier is missing or not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if either tier is not found or is not an IntervalTier.
+    pub fn difference_tier(&self, ref_tier: &str, hyp_tier: &str, result_name: String) -> Result<Tier, TextGridError> {
+        let mut tiers = Vec::with_capacity(2);
+        for name in [ref_tier, hyp_tier] {
+            let tier = self.get_tier(name).ok_or(TextGridError::Format("Tier not found".into()))?;
+            if tier.tier_type != TierType::IntervalTier {
+                return Err(TextGridError::Format(format!("Tier '{}' is not an IntervalTier", name)));
+            }
+            let mut tier = tier.clone();
+            tier.sort_intervals();
+            tiers.push(tier);
+        }
+
+        let mut boundaries: Vec<f64> = tiers
+            .iter()
+            .flat_map(|tier| tier.intervals.iter().flat_map(|i| [i.xmin, i.xmax]))
+            .chain([self.xmin, self.xmax])
+            .filter(|&time| time >= self.xmin && time <= self.xmax)
+            .collect();
+        boundaries.sort_by(f64::total_cmp);
+        boundaries.dedup_by(|a, b| (*a - *b).abs() <= BOUNDARY_EPSILON);
+
+        fn label_at(tier: &Tier, time: f64) -> &str {
+            tier.interval_at(time).map_or("", |i| i.text.trim())
+        }
+        let intervals = boundaries
+            .windows(2)
+            .map(|pair| {
+                let middle = (pair[0] + pair[1]) / 2.0;
+                let agrees = label_at(&tiers[0], middle) == label_at(&tiers[1], middle);
+                Interval { xmin: pair[0], xmax: pair[1], text: if agrees { "match" } else { "diff" }.to_string(), score: None }
+            })
+            .collect();
+
+        Ok(Tier {
+            name: result_name,
+            tier_type: TierType::IntervalTier,
+            xmin: self.xmin,
+            xmax: self.xmax,
+            intervals,
+            points: Vec::new(),
+        })
+    }
+
+    /// Builds the complement of an IntervalTier as a new, gapless tier spanning the grid's bounds.
+    ///
+    /// Wherever the source tier has a labeled interval, the result has an empty interval; every gap
+    /// or empty interval in the source becomes an interval labeled `label`. Adjacent regions of the
+    /// same kind are merged. The TextGrid itself is left unchanged.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the source IntervalTier.
+    /// * `result_name` - Name of the new tier.
+    /// * `label` - Text for the complement intervals.
+    ///
+    /// # Returns
+    /// Returns the new tier, or a `TextGridError` if the source tier is missing or not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not found or is not an IntervalTier.
+    pub fn invert_tier(&self, tier_name: &str, result_name: String, label: &str) -> Result<Tier, TextGridError> {
+        self.invert_tier_with(tier_name, result_name, label, &EmptyPolicy::Blank)
+    }
+
+    /// Builds the complement of an IntervalTier as in [`TextGrid::invert_tier`], with empty intervals
+    /// decided by an `EmptyPolicy`.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the source IntervalTier.
+    /// * `result_name` - Name of the new tier.
+    /// * `label` - Text for the complement intervals.
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns the new tier, or a `TextGridError` if the source tier is missing or not an IntervalTier.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not found or is not an IntervalTier.
+    pub fn invert_tier_with(&self, tier_name: &str, result_name: String, label: &str, policy: &EmptyPolicy) -> Result<Tier, TextGridError> {
+        let tier = self.get_tier(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if tier.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Tier is not an IntervalTier".into()));
+        }
+        let mut labeled: Vec<(f64, f64)> = tier
+            .intervals
+            .iter()
+            .filter(|i| !policy.treats_as_empty(&i.text))
+            .map(|i| (i.xmin.max(self.xmin), i.xmax.min(self.xmax)))
+            .filter(|(start, end)| start < end)
+            .collect();
+        labeled.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut intervals: Vec<Interval> = Vec::new();
+        let mut cursor = self.xmin;
+        for (start, end) in labeled {
+            if start > cursor {
+                intervals.push(Interval { xmin: cursor, xmax: start, text: label.to_string(), score: None });
+                intervals.push(Interval { xmin: start, xmax: end, text: String::new(), score: None });
+            } else if end > cursor {
+                match intervals.last_mut() {
+                    Some(last) if last.text.is_empty() => last.xmax = end,
+                    _ => intervals.push(Interval { xmin: cursor, xmax: end, text: String::new(), score: None }),
                 }
-                Change::AdjustBounds(old_xmin, old_xmax) => {
-                    let new_xmin = self.xmin;
-                    let new_xmax = self.xmax;
-                    self.xmin = old_xmin;
-                    self.xmax = old_xmax;
-                    for tier in &mut self.tiers {
-                        tier.xmin = old_xmin;
-                        tier.xmax = old_xmax;
+            }
+            cursor = cursor.max(end);
+        }
+        if cursor < self.xmax {
+            intervals.push(Interval { xmin: cursor, xmax: self.xmax, text: label.to_string(), score: None });
+        }
+
+        Ok(Tier {
+            name: result_name,
+            tier_type: TierType::IntervalTier,
+            xmin: self.xmin,
+            xmax: self.xmax,
+            intervals,
+            points: Vec::new(),
+        })
+    }
+
+    /// Builds a word tier by grouping consecutive labeled phones, with undo support.
+    ///
+    /// Blank phone intervals (pauses) are skipped when counting, so each word spans from the start of
+    /// its first phone to the end of its last. Gaps between words are left unannotated. The new tier
+    /// has the phone tier's bounds and is appended to the TextGrid.
+    ///
+    /// # Arguments
+    /// * `phone_tier` - Name of the phone IntervalTier.
+    /// * `words` - Each word with the number of phones it spans, in order.
+    /// * `result_name` - Name of the new word tier.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier cannot be built.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the phone tier is not found or not an IntervalTier, a tier named
+    ///   `result_name` already exists, a word spans zero phones, or the phone counts do not sum to the
+    ///   number of labeled phones.
+    pub fn build_word_tier_from_phones(&mut self, phone_tier: &str, words: &[(String, usize)], result_name: String) -> Result<(), TextGridError> {
+        let tier = self.get_tier(phone_tier).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if tier.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format("Tier is not an IntervalTier".into()));
+        }
+        self.check_new_tier_name(&result_name)?;
+        let mut phones: Vec<&Interval> = tier.intervals.iter().filter(|i| !is_blank(&i.text)).collect();
+        phones.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
+        let expected: usize = words.iter().map(|(_, count)| count).sum();
+        if expected != phones.len() {
+            return Err(TextGridError::Format(format!(
+                "Words span {} phones but tier '{}' has {} labeled phones",
+                expected,
+                phone_tier,
+                phones.len()
+            )));
+        }
+
+        let mut intervals = Vec::with_capacity(words.len());
+        let mut start = 0;
+        for (word, count) in words {
+            if *count == 0 {
+                return Err(TextGridError::Format(format!("Word '{}' spans no phones", word)));
+            }
+            let group = &phones[start..start + count];
+            intervals.push(Interval { xmin: group[0].xmin, xmax: group[count - 1].xmax, text: word.clone(), score: None });
+            start += count;
+        }
+
+        let words_tier = Tier {
+            name: result_name,
+            tier_type: TierType::IntervalTier,
+            xmin: tier.xmin,
+            xmax: tier.xmax,
+            intervals,
+            points: Vec::new(),
+        };
+        self.add_tier(words_tier)
+    }
+
+    /// Joins the non-empty labels of a tier into a transcript.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `separator` - String placed between consecutive labels.
+    ///
+    /// # Returns
+    /// Returns the transcript, or a `TextGridError` if the tier is not found.
+    pub fn transcript(&self, tier_name: &str, separator: &str) -> Result<String, TextGridError> {
+        self.transcript_with(tier_name, separator, &EmptyPolicy::Blank)
+    }
+
+    /// Joins the labels of a tier that are not empty under an `EmptyPolicy` into a transcript.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `separator` - String placed between consecutive labels.
+    /// * `policy` - Decides which labels count as empty.
+    ///
+    /// # Returns
+    /// Returns the transcript, or a `TextGridError` if the tier is not found.
+    pub fn transcript_with(&self, tier_name: &str, separator: &str, policy: &EmptyPolicy) -> Result<String, TextGridError> {
+        let tier = self.get_tier(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        Ok(tier.transcript_with(separator, policy))
+    }
+
+    /// Counts each ordered pair of consecutive labels, summed over all tiers.
+    ///
+    /// Transitions never span two tiers; see [`Tier::transition_counts`].
+    ///
+    /// # Returns
+    /// Returns a map from `(previous, next)` label pairs to their total counts.
+    pub fn transition_counts(&self) -> HashMap<(String, String), usize> {
+        self.transition_counts_with(BlankHandling::Skip)
+    }
+
+    /// Counts each ordered pair of consecutive labels over all tiers, with blank labels handled as chosen.
+    ///
+    /// # Arguments
+    /// * `blanks` - Whether blank labels are skipped or break the sequence; see [`Tier::transition_counts_with`].
+    ///
+    /// # Returns
+    /// Returns a map from `(previous, next)` label pairs to their total counts.
+    pub fn transition_counts_with(&self, blanks: BlankHandling) -> HashMap<(String, String), usize> {
+        let mut counts = HashMap::new();
+        for tier in &self.tiers {
+            for (pair, count) in tier.transition_counts_with(blanks) {
+                *counts.entry(pair).or_insert(0) += count;
+            }
+        }
+        counts
+    }
+
+    /// Computes the number of non-empty intervals per second in a tier.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `per` - Which duration to divide the count by.
+    ///
+    /// # Returns
+    /// Returns the rate in labels per second (`0.0` for a zero duration), or a `TextGridError` if the tier is not found.
+    pub fn rate(&self, tier_name: &str, per: RateDenominator) -> Result<f64, TextGridError> {
+        let tier = self.get_tier(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        Ok(tier.rate(per))
+    }
+
+    /// Sums the durations of intervals matching any of the given labels across all tiers.
+    ///
+    /// # Arguments
+    /// * `labels` - The labels to match exactly.
+    ///
+    /// # Returns
+    /// Returns the total duration in seconds over every IntervalTier.
+    pub fn duration_of_labels(&self, labels: &HashSet<String>) -> f64 {
+        self.tiers.iter().map(|tier| tier.duration_of_labels(labels)).sum()
+    }
+
+    /// Lists the tiers without any non-empty interval text or point mark.
+    ///
+    /// # Returns
+    /// Returns the names of the empty tiers, in tier order.
+    pub fn empty_tiers(&self) -> Vec<&str> {
+        self.tiers.iter().filter(|tier| tier.is_empty_tier()).map(|tier| tier.name.as_str()).collect()
+    }
+
+    /// Lists the IntervalTiers that do not tile their span, as decided by [`Tier::is_gapless`].
+    ///
+    /// # Arguments
+    /// * `epsilon` - Tolerance, in seconds, for boundaries to count as touching.
+    ///
+    /// # Returns
+    /// Returns the names of the IntervalTiers with gaps or uncovered edges, in tier order.
+    pub fn non_gapless_tiers(&self, epsilon: f64) -> Vec<&str> {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.tier_type == TierType::IntervalTier && !tier.is_gapless(epsilon))
+            .map(|tier| tier.name.as_str())
+            .collect()
+    }
+
+    /// Removes every tier without any non-empty interval text or point mark, as a single undoable change.
+    ///
+    /// # Returns
+    /// Returns the number of tiers removed.
+    pub fn remove_empty_tiers(&mut self) -> usize {
+        let kept: Vec<Tier> = self.tiers.iter().filter(|tier| !tier.is_empty_tier()).cloned().collect();
+        let removed = self.tiers.len() - kept.len();
+        if removed > 0 {
+            let previous = self.replace_grid(self.xmin, self.xmax, kept);
+            self.save_change(previous);
+        }
+        removed
+    }
+
+    /// Counts empty intervals across all tiers.
+    ///
+    /// # Returns
+    /// Returns the total number of intervals with empty (blank) text.
+    pub fn empty_interval_count(&self) -> usize {
+        self.tiers.iter().map(Tier::empty_interval_count).sum()
+    }
+
+    /// Splits the TextGrid into one single-tier TextGrid per tier.
+    ///
+    /// # Returns
+    /// Returns the new TextGrids in tier order, each with the original bounds and an empty history.
+    pub fn split_into_single_tier_grids(&self) -> Vec<TextGrid> {
+        self.tiers
+            .iter()
+            .map(|tier| TextGrid {
+                xmin: self.xmin,
+                xmax: self.xmax,
+                tiers: vec![tier.clone()],
+                history: VecDeque::new(),
+                redo_stack: VecDeque::new(),
+                max_history: self.max_history,
+                allow_duplicate_tier_names: self.allow_duplicate_tier_names,
+            })
+            .collect()
+    }
+
+    /// Checks that the TextGrid has exactly the given tiers, by name and type, in order.
+    ///
+    /// # Arguments
+    /// * `schema` - The expected tier names and types, in order.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the tiers match the schema or a `TextGridError` describing the first mismatch.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if a schema tier is missing, a tier is not in the schema, a tier has the
+    ///   wrong type, or the tiers are in a different order.
+    pub fn matches_schema(&self, schema: &[(String, TierType)]) -> Result<(), TextGridError> {
+        for (position, (name, tier_type)) in schema.iter().enumerate() {
+            let Some(tier) = self.tiers.get(position) else {
+                return Err(TextGridError::Format(format!("Missing tier '{}'", name)));
+            };
+            if tier.name != *name {
+                let message = if self.get_tier(name).is_none() {
+                    format!("Missing tier '{}'", name)
+                } else if !schema.iter().any(|(expected, _)| *expected == tier.name) {
+                    format!("Unexpected tier '{}'", tier.name)
+                } else {
+                    format!("Expected tier '{}' at position {}, found '{}'", name, position + 1, tier.name)
+                };
+                return Err(TextGridError::Format(message));
+            }
+            if tier.tier_type != *tier_type {
+                return Err(TextGridError::Format(format!(
+                    "Tier '{}' should be {:?} but is {:?}",
+                    name, tier_type, tier.tier_type
+                )));
+            }
+        }
+        if let Some(extra) = self.tiers.get(schema.len()) {
+            return Err(TextGridError::Format(format!("Unexpected tier '{}'", extra.name)));
+        }
+        Ok(())
+    }
+
+    /// Finds every interval text or point mark longer than `max_bytes` bytes of UTF-8.
+    ///
+    /// # Arguments
+    /// * `max_bytes` - The maximum allowed label length in bytes.
+    ///
+    /// # Returns
+    /// Returns `(tier name, item index, length in bytes)` for each over-long label, in tier and item order.
+    pub fn find_overlong_labels(&self, max_bytes: usize) -> Vec<(&str, usize, usize)> {
+        self.tiers
+            .iter()
+            .flat_map(|tier| {
+                let labels: Vec<&str> = match tier.tier_type {
+                    TierType::IntervalTier => tier.intervals.iter().map(|i| i.text.as_str()).collect(),
+                    TierType::PointTier => tier.points.iter().map(|p| p.mark.as_str()).collect(),
+                };
+                labels
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, label)| label.len() > max_bytes)
+                    .map(|(index, label)| (tier.name.as_str(), index, label.len()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Checks that no interval text or point mark is longer than `max_bytes` bytes of UTF-8.
+    ///
+    /// # Arguments
+    /// * `max_bytes` - The maximum allowed label length in bytes.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if every label fits or a `TextGridError` describing the first over-long label.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` naming the tier, item index and length of the first over-long label.
+    pub fn check_label_lengths(&self, max_bytes: usize) -> Result<(), TextGridError> {
+        if let Some((tier, index, length)) = self.find_overlong_labels(max_bytes).first() {
+            return Err(TextGridError::Format(format!(
+                "Label {} in tier '{}' is {} bytes long, exceeding the limit of {}",
+                index, tier, length, max_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the grid's bounds together with its duration.
+    ///
+    /// # Returns
+    /// Returns `(xmin, xmax, xmax - xmin)`.
+    pub fn bounds_summary(&self) -> (f64, f64, f64) {
+        (self.xmin, self.xmax, self.xmax - self.xmin)
+    }
+
+    /// Checks that the grid's duration is within `tolerance` of `expected`.
+    ///
+    /// # Arguments
+    /// * `expected` - The expected duration in seconds.
+    /// * `tolerance` - The maximum allowed absolute difference in seconds.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the duration matches or a `TextGridError` otherwise.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the duration differs from `expected` by more than `tolerance`,
+    ///   or if either argument is NaN.
+    pub fn assert_duration(&self, expected: f64, tolerance: f64) -> Result<(), TextGridError> {
+        let (_, _, duration) = self.bounds_summary();
+        let difference = (duration - expected).abs();
+        if difference > tolerance || difference.is_nan() || tolerance.is_nan() {
+            return Err(TextGridError::Format(format!(
+                "Expected duration {} +/- {}, found {}",
+                expected, tolerance, duration
+            )));
+        }
+        Ok(())
+    }
+
+    /// Summarizes each tier's size, labeling and coverage.
+    ///
+    /// # Returns
+    /// Returns one `TierReport` per tier, in tier order.
+    pub fn tier_reports(&self) -> Vec<TierReport> {
+        self.tiers
+            .iter()
+            .map(|tier| {
+                let (item_count, labeled_count) = match tier.tier_type {
+                    TierType::IntervalTier => (tier.intervals.len(), tier.nonempty_interval_count()),
+                    TierType::PointTier => (tier.points.len(), tier.points.len() - tier.empty_point_count()),
+                };
+                let labeled_duration: f64 =
+                    tier.intervals.iter().filter(|i| !is_blank(&i.text)).map(|i| i.xmax - i.xmin).sum();
+                let duration = tier.xmax - tier.xmin;
+                let gap_count = match tier.tier_type {
+                    TierType::IntervalTier => {
+                        let mut covered_to = tier.xmin;
+                        let mut gaps = 0;
+                        for interval in &tier.intervals {
+                            if interval.xmin - covered_to > BOUNDARY_EPSILON {
+                                gaps += 1;
+                            }
+                            covered_to = covered_to.max(interval.xmax);
+                        }
+                        if tier.xmax - covered_to > BOUNDARY_EPSILON {
+                            gaps += 1;
+                        }
+                        gaps
                     }
-                    self.redo_stack.push_back(Change::AdjustBounds(new_xmin, new_xmax));
-                }
-                Change::InsertSilence(tier_name, before, _) => {
-                    let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-                    let after = tier.intervals.clone();
-                    tier.intervals = before.clone();
-                    self.redo_stack.push_back(Change::InsertSilence(tier_name, before, after));
+                    TierType::PointTier => 0,
+                };
+                TierReport {
+                    name: tier.name.clone(),
+                    tier_type: tier.tier_type,
+                    item_count,
+                    labeled_count,
+                    labeled_duration,
+                    coverage: if duration > 0.0 { labeled_duration / duration } else { 0.0 },
+                    gap_count,
                 }
+            })
+            .collect()
+    }
+
+    /// Finds the time ranges where every `(tier_name, label)` condition holds at once.
+    ///
+    /// Each condition matches the intervals of the named tier whose text equals the label; the result is
+    /// the intersection of those intervals across all conditions. Touching ranges are not merged.
+    ///
+    /// # Arguments
+    /// * `conditions` - Pairs of tier name and required label.
+    ///
+    /// # Returns
+    /// Returns the non-empty `(start, end)` ranges in time order, or an empty vector if there are no
+    /// conditions, a tier is missing, or the conditions never hold together.
+    pub fn query_cooccurrence(&self, conditions: &[(&str, &str)]) -> Vec<(f64, f64)> {
+        let matching = |&(tier_name, label): &(&str, &str)| -> Vec<(f64, f64)> {
+            self.get_tier(tier_name)
+                .map(|tier| tier.intervals.iter().filter(|i| i.text == label).map(|i| (i.xmin, i.xmax)).collect())
+                .unwrap_or_default()
+        };
+        let Some((first, rest)) = conditions.split_first() else {
+            return Vec::new();
+        };
+        let mut ranges = matching(first);
+        ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for condition in rest {
+            let others = matching(condition);
+            ranges = ranges
+                .iter()
+                .flat_map(|&(start, end)| {
+                    others.iter().filter_map(move |&(other_start, other_end)| {
+                        let (xmin, xmax) = (start.max(other_start), end.min(other_end));
+                        (xmin < xmax).then_some((xmin, xmax))
+                    })
+                })
+                .collect();
+            ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        ranges
+    }
+
+    /// Shifts every time value so the TextGrid starts at zero, with undo support.
+    ///
+    /// The grid's `xmin` is subtracted from all bounds, intervals and points, so the grid spans
+    /// `[0, xmax - xmin]`. A grid already starting at zero is left unchanged.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the grid bounds are invalid.
+    pub fn rebase_to_zero(&mut self) -> Result<(), TextGridError> {
+        if self.xmin >= self.xmax {
+            return Err(TextGridError::Format("TextGrid xmin must be less than xmax".into()));
+        }
+        if self.xmin == 0.0 {
+            return Ok(());
+        }
+        let offset = -self.xmin;
+        let mut tiers = self.tiers.clone();
+        for tier in &mut tiers {
+            shift_tier(tier, offset);
+        }
+        let previous = self.replace_grid(0.0, self.xmax + offset, tiers);
+        self.save_change(previous);
+        Ok(())
+    }
+
+    /// Shifts every time value by `delta` seconds as a single undoable change.
+    ///
+    /// The offset is added to the grid bounds, every tier's bounds, and every interval and point.
+    ///
+    /// # Arguments
+    /// * `delta` - Offset in seconds; may be negative.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the shift is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `delta` is not finite or the shift would move any time below zero.
+    pub fn shift_time(&mut self, delta: f64) -> Result<(), TextGridError> {
+        self.shift_time_with(delta, false)
+    }
+
+    /// Shifts every time value as in [`TextGrid::shift_time`], optionally allowing negative times.
+    ///
+    /// # Arguments
+    /// * `delta` - Offset in seconds; may be negative.
+    /// * `allow_negative` - If `true`, the shift may move times below zero.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the shift is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `delta` is not finite, or if `allow_negative` is `false` and the
+    ///   shift would move any time below zero.
+    pub fn shift_time_with(&mut self, delta: f64, allow_negative: bool) -> Result<(), TextGridError> {
+        if !delta.is_finite() {
+            return Err(TextGridError::Format("Time shift must be finite".into()));
+        }
+        let earliest = self.tiers.iter().map(|tier| tier.xmin).fold(self.xmin, f64::min);
+        if !allow_negative && earliest + delta < 0.0 {
+            return Err(TextGridError::Format(format!(
+                "Shifting by {} would move time {} below zero",
+                delta, earliest
+            )));
+        }
+        let mut tiers = self.tiers.clone();
+        for tier in &mut tiers {
+            shift_tier(tier, delta);
+        }
+        let previous = self.replace_grid(self.xmin + delta, self.xmax + delta, tiers);
+        self.save_change(previous);
+        Ok(())
+    }
+
+    /// Multiplies every time value by `factor` as a single undoable change.
+    ///
+    /// The grid bounds, every tier's bounds, and every interval and point are scaled, so durations
+    /// scale proportionally and ordering is preserved.
+    ///
+    /// # Arguments
+    /// * `factor` - Scale factor; must be positive and finite.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the factor is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if `factor` is not positive and finite.
+    pub fn scale_time(&mut self, factor: f64) -> Result<(), TextGridError> {
+        if factor <= 0.0 || !factor.is_finite() {
+            return Err(TextGridError::Format("Time scale factor must be positive".into()));
+        }
+        let mut tiers = self.tiers.clone();
+        for tier in &mut tiers {
+            tier.xmin *= factor;
+            tier.xmax *= factor;
+            for interval in &mut tier.intervals {
+                interval.xmin *= factor;
+                interval.xmax *= factor;
             }
-            Ok(())
-        } else {
-            Err(TextGridError::Format("No more actions to undo".into()))
+            for point in &mut tier.points {
+                point.time *= factor;
+            }
+        }
+        let previous = self.replace_grid(self.xmin * factor, self.xmax * factor, tiers);
+        self.save_change(previous);
+        Ok(())
+    }
+
+    /// Snaps drifted shared boundaries back together across all IntervalTiers, with undo support.
+    ///
+    /// Each IntervalTier is snapped as by the binary writer's `snap_epsilon`: an interval's `xmin` is
+    /// set to the previous interval's `xmax`, the first `xmin` to the tier's `xmin`, and the last
+    /// `xmax` to the tier's `xmax`, whenever they differ by a nonzero amount of at most `epsilon`.
+    /// Real gaps wider than `epsilon` are left alone. Nothing is recorded if no boundary needed adjusting.
+    ///
+    /// # Arguments
+    /// * `epsilon` - Maximum drift, in seconds, to repair.
+    ///
+    /// # Returns
+    /// Returns the number of boundaries adjusted.
+    pub fn canonicalize_boundaries(&mut self, epsilon: f64) -> usize {
+        let mut tiers = self.tiers.clone();
+        let mut adjusted = 0;
+        for tier in tiers.iter_mut().filter(|tier| tier.tier_type == TierType::IntervalTier) {
+            adjusted += tier.snap_boundaries(epsilon);
+        }
+        if adjusted > 0 {
+            let previous = self.replace_grid(self.xmin, self.xmax, tiers);
+            self.save_change(previous);
+        }
+        adjusted
+    }
+
+    /// Captures all time values (grid, tier, interval and point bounds), leaving out labels.
+    ///
+    /// # Returns
+    /// Returns a `TimeSnapshot` that can be reapplied with [`TextGrid::restore_times`].
+    pub fn snapshot_times(&self) -> TimeSnapshot {
+        TimeSnapshot {
+            bounds: (self.xmin, self.xmax),
+            tiers: self
+                .tiers
+                .iter()
+                .map(|tier| TierTimes {
+                    tier_type: tier.tier_type,
+                    bounds: (tier.xmin, tier.xmax),
+                    intervals: tier.intervals.iter().map(|i| (i.xmin, i.xmax)).collect(),
+                    points: tier.points.iter().map(|p| p.time).collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Reapplies time values captured by [`TextGrid::snapshot_times`], keeping current labels, with undo support.
+    ///
+    /// # Arguments
+    /// * `snap` - The snapshot to restore.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the structure no longer matches the snapshot.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the number or type of tiers, or the number of intervals or points in a tier,
+    ///   differs from when the snapshot was taken.
+    pub fn restore_times(&mut self, snap: TimeSnapshot) -> Result<(), TextGridError> {
+        let matches = snap.tiers.len() == self.tiers.len()
+            && snap.tiers.iter().zip(&self.tiers).all(|(times, tier)| {
+                times.tier_type == tier.tier_type
+                    && times.intervals.len() == tier.intervals.len()
+                    && times.points.len() == tier.points.len()
+            });
+        if !matches {
+            return Err(TextGridError::Format("TextGrid structure does not match the time snapshot".into()));
+        }
+
+        let mut tiers = self.tiers.clone();
+        for (tier, times) in tiers.iter_mut().zip(snap.tiers) {
+            (tier.xmin, tier.xmax) = times.bounds;
+            for (interval, (xmin, xmax)) in tier.intervals.iter_mut().zip(times.intervals) {
+                interval.xmin = xmin;
+                interval.xmax = xmax;
+            }
+            for (point, time) in tier.points.iter_mut().zip(times.points) {
+                point.time = time;
+            }
+        }
+        let (xmin, xmax) = snap.bounds;
+        let previous = self.replace_grid(xmin, xmax, tiers);
+        self.save_change(previous);
+        Ok(())
+    }
+
+    /// Collects the distinct interval boundary times across all IntervalTiers.
+    ///
+    /// This is the minimal common segmentation of the grid: the sorted union of every interval
+    /// `xmin` and `xmax`, with times closer than `1e-9` seconds treated as one boundary.
+    ///
+    /// # Returns
+    /// Returns the deduplicated boundary times in ascending order.
+    pub fn boundary_times(&self) -> Vec<f64> {
+        let mut times: Vec<f64> = self
+            .tiers
+            .iter()
+            .filter(|t| t.tier_type == TierType::IntervalTier)
+            .flat_map(|t| t.intervals.iter().flat_map(|i| [i.xmin, i.xmax]))
+            .collect();
+        times.sort_by(|a, b| a.total_cmp(b));
+        times.dedup_by(|a, b| (*a - *b).abs() <= BOUNDARY_EPSILON);
+        times
+    }
+
+    /// Converts a tier's annotations to sample-indexed segments.
+    ///
+    /// Intervals become `(start, end, text)` triples; points become zero-length `(sample, sample, mark)`
+    /// triples. Times are rounded to the nearest sample, with halves rounded away from zero.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier to convert.
+    /// * `sample_rate` - Sampling rate in Hz.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the segments in tier order or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not found or `sample_rate` is not positive.
+    /// - `TextGridError::InvalidTime` if any time is negative.
+    pub fn tier_to_sample_segments(&self, tier_name: &str, sample_rate: f64) -> Result<Vec<(usize, usize, String)>, TextGridError> {
+        let tier = self.get_tier(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        match tier.tier_type {
+            TierType::IntervalTier => tier
+                .intervals
+                .iter()
+                .map(|i| i.to_samples(sample_rate).map(|(start, end)| (start, end, i.text.clone())))
+                .collect(),
+            TierType::PointTier => tier
+                .points
+                .iter()
+                .map(|p| p.to_sample(sample_rate).map(|sample| (sample, sample, p.mark.clone())))
+                .collect(),
         }
     }
-    
-    /// Redoes the last undone change.
+
+    /// Queries all tiers for intervals containing the specified time.
+    ///
+    /// # Arguments
+    /// * `time` - Time to search for.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if there are no changes to redo or if the redo fails.
-    pub fn redo(&mut self) -> Result<(), TextGridError> {
-        if let Some(change) = self.redo_stack.pop_back() {
-            match change {
-                Change::AddTier(tier) => {
-                    self.tiers.push(tier.clone());
-                    self.save_change(Change::AddTier(tier));
-                }
-                Change::RemoveTier(index, tier) => {
-                    if index < self.tiers.len() && self.tiers[index].name == tier.name {
-                        let removed = self.tiers.remove(index);
-                        self.save_change(Change::RemoveTier(index, removed));
-                    } else {
-                        return Err(TextGridError::Format("Tier not found or index mismatch for redo".into()));
-                    }
-                }
-                Change::AddInterval(tier_name, interval) => {
-                    self.tier_add_interval(&tier_name, interval)?;
-                }
-                Change::RemoveInterval(tier_name, index, interval) => {
-                    self.tier_remove_interval(&tier_name, index)?;
-                }
-                Change::AddPoint(tier_name, point) => {
-                    self.tier_add_point(&tier_name, point)?;
-                }
-                Change::RemovePoint(tier_name, index, point) => {
-                    self.tier_remove_point(&tier_name, index)?;
-                }
-                Change::SplitInterval(tier_name, index, orig, left) => {
-                    self.tier_split_interval(&tier_name, index, left.xmax)?;
-                }
-                Change::MergeIntervals(tier_name, before, after) => {
-                    self.tier_merge_intervals(&tier_name)?;
-                }
-                Change::RenameTier(old_name, new_name) => {
-                    self.rename_tier(&old_name, new_name)?;
-                }
-                Change::MergeTiers(t1, t2, new_name, tier) => {
-                    self.add_tier(tier)?;
-                }
-                Change::AdjustBounds(new_xmin, new_xmax) => {
-                    self.adjust_bounds(new_xmin, new_xmax)?;
-                }
-                Change::InsertSilence(tier_name, before, after) => {
-                    let tier = self.get_tier_mut(&tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-                    tier.intervals = after.clone();
-                    self.save_change(Change::InsertSilence(tier_name, before, after));
-                }
-            }
-            Ok(())
-        } else {
-            Err(TextGridError::Format("No more actions to redo".into()))
-        }
+    /// Returns a vector of tuples containing tiers and their matching intervals.
+    pub fn query_intervals_by_time(&self, time: f64) -> Vec<(&Tier, Vec<&Interval>)> {
+        self.tiers.iter().map(|t| (t, t.find_intervals_by_time(time))).filter(|(_, v)| !v.is_empty()).collect()
     }
 
-    /// Adds a tier to the TextGrid with undo support.
+    /// Queries all tiers for points at the specified time.
     ///
     /// # Arguments
-    /// * `tier` - The tier to add.
+    /// * `time` - Time to search for.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier bounds are invalid.
-    pub fn add_tier(&mut self, tier: Tier) -> Result<(), TextGridError> {
-        if tier.xmin < self.xmin || tier.xmax > self.xmax {
-            return Err(TextGridError::Format("Tier bounds must be within TextGrid bounds".into()));
-        }
-        self.save_change(Change::AddTier(tier.clone()));
-        self.tiers.push(tier);
+    /// Returns a vector of tuples containing tiers and their matching points.
+    pub fn query_points_by_time(&self, time: f64) -> Vec<(&Tier, Vec<&Point>)> {
+        self.tiers.iter().map(|t| (t, t.find_points_by_time(time))).filter(|(_, v)| !v.is_empty()).collect()
+    }
+
+    /// Queries all tiers for intervals containing the specified text substring.
+    ///
+    /// # Arguments
+    /// * `text` - Substring to search for in interval texts.
+    ///
+    /// # Returns
+    /// Returns a vector of tuples containing tiers and their matching intervals.
+    pub fn query_intervals_by_text(&self, text: &str) -> Vec<(&Tier, Vec<&Interval>)> {
+        self.tiers.iter().map(|t| (t, t.find_intervals_by_text(text))).filter(|(_, v)| !v.is_empty()).collect()
+    }
+
+    /// Adds an interval to a tier with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `interval` - Interval to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_add_interval(&mut self, tier_name: &str, interval: Interval) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        tier.add_interval(interval.clone())?;
+        self.save_change(Change::AddInterval(tier_name.to_string(), interval));
         Ok(())
     }
 
-    /// Removes a tier from the TextGrid by index with undo support.
+    /// Removes an interval from a tier with undo support.
     ///
     /// # Arguments
-    /// * `index` - Index of the tier to remove.
+    /// * `tier_name` - Name of the tier.
+    /// * `index` - Index of the interval to remove.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the index is out of bounds.
-    pub fn remove_tier(&mut self, index: usize) -> Result<(), TextGridError> {
-        if index >= self.tiers.len() {
-            return Err(TextGridError::Format("Tier index out of bounds".into()));
-        }
-        let tier = self.tiers.remove(index);
-        self.save_change(Change::RemoveTier(index, tier));
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_remove_interval(&mut self, tier_name: &str, index: usize) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let interval = tier.remove_interval(index)?;
+        self.save_change(Change::RemoveInterval(tier_name.to_string(), index, interval));
         Ok(())
     }
 
-    /// Gets a mutable reference to a tier by name.
+    /// Adds a point to a tier with undo support.
     ///
     /// # Arguments
-    /// * `name` - Name of the tier to find.
+    /// * `tier_name` - Name of the tier.
+    /// * `point` - Point to add.
     ///
     /// # Returns
-    /// Returns an `Option` containing a mutable reference to the tier if found, or `None` if not.
-    pub fn get_tier_mut(&mut self, name: &str) -> Option<&mut Tier> {
-        self.tiers.iter_mut().find(|t| t.name == name)
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_add_point(&mut self, tier_name: &str, point: Point) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        tier.add_point(point.clone())?;
+        self.save_change(Change::AddPoint(tier_name.to_string(), point));
+        Ok(())
     }
 
-    /// Gets an immutable reference to a tier by name.
+    /// Removes a point from a tier with undo support.
     ///
     /// # Arguments
-    /// * `name` - Name of the tier to find.
+    /// * `tier_name` - Name of the tier.
+    /// * `index` - Index of the point to remove.
     ///
     /// # Returns
-    /// Returns an `Option` containing a reference to the tier if found, or `None` if not.
-    pub fn get_tier(&self, name: &str) -> Option<&Tier> {
-        self.tiers.iter().find(|t| t.name == name)
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_remove_point(&mut self, tier_name: &str, index: usize) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let point = tier.remove_point(index)?;
+        self.save_change(Change::RemovePoint(tier_name.to_string(), index, point));
+        Ok(())
     }
 
-    /// Renames a tier with undo support.
+    /// Splits an interval in a tier with undo support.
     ///
     /// # Arguments
-    /// * `old_name` - Current name of the tier.
-    /// * `new_name` - New name for the tier.
+    /// * `tier_name` - Name of the tier.
+    /// * `index` - Index of the interval to split.
+    /// * `time` - Time at which to split the interval.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found.
-    pub fn rename_tier(&mut self, old_name: &str, new_name: String) -> Result<(), TextGridError> {
-        if let Some(tier) = self.get_tier_mut(old_name) {
-            let old_name = tier.rename(new_name.clone());
-            self.save_change(Change::RenameTier(old_name, new_name));
-            Ok(())
-        } else {
-            Err(TextGridError::Format("Tier not found".into()))
-        }
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_split_interval(&mut self, tier_name: &str, index: usize, time: f64) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let orig = tier.intervals[index].clone();
+        let (left, _) = tier.split_interval(index, time)?;
+        self.save_change(Change::SplitInterval(tier_name.to_string(), index, orig, left));
+        Ok(())
     }
 
-    /// Merges two tiers using a custom strategy with undo support.
+    /// Splits an interval in a tier with distinct texts for each half, with undo support.
     ///
     /// # Arguments
-    /// * `name1` - Name of the first tier.
-    /// * `name2` - Name of the second tier.
-    /// * `new_name` - Name for the resulting merged tier.
-    /// * `merge_strategy` - Function to determine how overlapping intervals are merged.
+    /// * `tier_name` - Name of the tier.
+    /// * `index` - Index of the interval to split.
+    /// * `time` - Time at which to split the interval.
+    /// * `left` - Text for the interval before `time`.
+    /// * `right` - Text for the interval after `time`.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tiers are not found or not IntervalTiers.
-    pub fn merge_tiers_with_strategy<F>(
-        &mut self,
-        name1: &str,
-        name2: &str,
-        new_name: String,
-        merge_strategy: F,
-    ) -> Result<(), TextGridError>
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_split_interval_with_texts(&mut self, tier_name: &str, index: usize, time: f64, left: String, right: String) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.split_interval_with_texts(index, time, left, right).map(|_| ()))
+    }
+
+    /// Merges intervals in a tier with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
+    pub fn tier_merge_intervals(&mut self, tier_name: &str) -> Result<(), TextGridError> {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let before = tier.merge_intervals()?;
+        let after = tier.intervals.clone();
+        self.save_change(Change::MergeIntervals(tier_name.to_string(), before, after));
+        Ok(())
+    }
+
+    /// Applies an edit to a tier, recording its intervals before and after for undo.
+    ///
+    /// Nothing is recorded if the edit leaves the intervals unchanged.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier to edit.
+    /// * `edit` - The edit to apply to the tier.
+    ///
+    /// # Returns
+    /// Returns the value produced by `edit` or a `TextGridError` if the tier is not found or the edit fails.
+    fn edit_tier_intervals<R, F>(&mut self, tier_name: &str, edit: F) -> Result<R, TextGridError>
     where
-        F: Fn(&Interval, &Interval) -> Option<Interval>,
+        F: FnOnce(&mut Tier) -> Result<R, TextGridError>,
     {
-        let tier1 = self.get_tier(name1).ok_or(TextGridError::Format("First tier not found".into()))?.clone();
-        let tier2 = self.get_tier(name2).ok_or(TextGridError::Format("Second tier not found".into()))?.clone();
-
-        if tier1.tier_type != TierType::IntervalTier || tier2.tier_type != TierType::IntervalTier {
-            return Err(TextGridError::Format("Can only merge IntervalTiers".into()));
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let before = tier.intervals.clone();
+        let result = edit(tier)?;
+        if tier.intervals != before {
+            let after = tier.intervals.clone();
+            self.save_change(Change::ReplaceIntervals(tier_name.to_string(), before, after));
         }
+        Ok(result)
+    }
 
-        let mut combined_intervals = Vec::new();
-        combined_intervals.extend(tier1.intervals.clone());
-        combined_intervals.extend(tier2.intervals.clone());
-        combined_intervals.sort_by(|a, b| a.xmin.partial_cmp(&b.xmin).unwrap());
+    /// Applies an edit to a tier, recording its points before and after for undo.
+    ///
+    /// Nothing is recorded if the edit leaves the points unchanged.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier to edit.
+    /// * `edit` - The edit to apply to the tier.
+    ///
+    /// # Returns
+    /// Returns the value produced by `edit` or a `TextGridError` if the tier is not found or the edit fails.
+    fn edit_tier_points<R, F>(&mut self, tier_name: &str, edit: F) -> Result<R, TextGridError>
+    where
+        F: FnOnce(&mut Tier) -> Result<R, TextGridError>,
+    {
+        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let before = tier.points.clone();
+        let result = edit(tier)?;
+        if tier.points != before {
+            let after = tier.points.clone();
+            self.save_change(Change::ReplacePoints(tier_name.to_string(), before, after));
+        }
+        Ok(result)
+    }
 
-        let mut new_intervals = Vec::new();
-        if !combined_intervals.is_empty() {
-            let mut current = combined_intervals[0].clone();
-            for next in combined_intervals.iter().skip(1) {
-                if current.xmax > next.xmin {
-                    if let Some(merged) = merge_strategy(&current, next) {
-                        current = merged;
-                    } else {
-                        new_intervals.push(current);
-                        current = next.clone();
-                    }
-                } else {
-                    new_intervals.push(current);
-                    current = next.clone();
-                }
+    /// Applies an arbitrary edit to a tier, then re-sorts and validates it, with undo support.
+    ///
+    /// If `f` fails or the edited tier is invalid, the tier is restored and nothing is recorded.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier to edit.
+    /// * `f` - The edit to apply.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found, `f` fails, or the result is invalid.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if the tier is not found, the edited tier fails validation, or its bounds
+    ///   leave the TextGrid's bounds.
+    /// - Any error returned by `f`.
+    pub fn with_tier_mut<F>(&mut self, tier_name: &str, f: F) -> Result<(), TextGridError>
+    where
+        F: FnOnce(&mut Tier) -> Result<(), TextGridError>,
+    {
+        let index = self.tiers.iter().position(|t| t.name == tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
+        let (xmin, xmax) = (self.xmin, self.xmax);
+        let tier = &mut self.tiers[index];
+        let before = tier.clone();
+        let result = f(tier).and_then(|()| {
+            tier.sort_intervals();
+            tier.sort_points();
+            if tier.xmin < xmin || tier.xmax > xmax {
+                return Err(TextGridError::Format("Tier bounds must be within TextGrid bounds".into()));
             }
-            new_intervals.push(current);
+            tier.validate()
+        });
+        if let Err(error) = result {
+            *tier = before;
+            return Err(error);
         }
+        let after = tier.clone();
+        self.save_change(Change::ReplaceTier(index, before, after));
+        Ok(())
+    }
+
+    /// Makes a tier's intervals contiguous with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `strategy` - Which side of each shared boundary moves.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the operation fails.
+    pub fn tier_make_contiguous(&mut self, tier_name: &str, strategy: ContiguityStrategy) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.make_contiguous(strategy))
+    }
+
+    /// Relabels a tier's intervals from a mapping table with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `map` - Mapping from old labels to new labels.
+    ///
+    /// # Returns
+    /// Returns the number of intervals changed, or a `TextGridError` if the tier is not found.
+    pub fn tier_apply_label_map(&mut self, tier_name: &str, map: &HashMap<String, String>) -> Result<usize, TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| Ok(tier.apply_label_map(map)))
+    }
+
+    /// Blanks a tier's intervals whose labels are not in `keep`, with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `keep` - The labels to keep.
+    ///
+    /// # Returns
+    /// Returns the number of intervals cleared, or a `TextGridError` if the tier is not found.
+    pub fn tier_keep_labels(&mut self, tier_name: &str, keep: &HashSet<String>) -> Result<usize, TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| Ok(tier.keep_labels(keep)))
+    }
 
-        let new_tier = Tier {
-            name: new_name.clone(),
-            tier_type: TierType::IntervalTier,
-            xmin: self.xmin,
-            xmax: self.xmax,
-            intervals: new_intervals,
-            points: Vec::new(),
-        };
-        self.save_change(Change::MergeTiers(name1.to_string(), name2.to_string(), new_name, new_tier.clone()));
-        self.tiers.push(new_tier);
-        Ok(())
+    /// Merges near-duplicate points in a tier with undo support.
+    ///
+    /// # Arguments
+    /// * `tier_name` - Name of the tier.
+    /// * `tolerance` - Maximum time difference for two points to count as the same event.
+    ///
+    /// # Returns
+    /// Returns the number of points removed, or a `TextGridError` if the tier is not found.
+    pub fn tier_dedup_points(&mut self, tier_name: &str, tolerance: f64) -> Result<usize, TextGridError> {
+        self.edit_tier_points(tier_name, |tier| Ok(tier.dedup_points(tolerance)))
     }
 
-    /// Merges two tiers with a default strategy (merges if text matches or one is empty).
+    /// Maps a tier's interval labels onto canonical symbols with undo support.
     ///
     /// # Arguments
-    /// * `name1` - Name of the first tier.
-    /// * `name2` - Name of the second tier.
-    /// * `new_name` - Name for the resulting merged tier.
+    /// * `tier_name` - Name of the tier.
+    /// * `canonical` - The canonical symbols, in order of preference.
+    /// * `matcher` - Called as `matcher(label, symbol)`; returns `true` if `label` is a variant of `symbol`.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tiers are not found or not IntervalTiers.
-    pub fn merge_tiers(&mut self, name1: &str, name2: &str, new_name: String) -> Result<(), TextGridError> {
-        self.merge_tiers_with_strategy(name1, name2, new_name, |current, next| {
-            if current.text == next.text || current.text.is_empty() || next.text.is_empty() {
-                Some(Interval {
-                    xmin: current.xmin,
-                    xmax: current.xmax.max(next.xmax),
-                    text: if current.text.is_empty() { next.text.clone() } else { current.text.clone() },
-                })
-            } else {
-                None
-            }
-        })
+    /// Returns the number of intervals changed, or a `TextGridError` if the tier is not found.
+    pub fn tier_canonicalize_labels(
+        &mut self,
+        tier_name: &str,
+        canonical: &[String],
+        matcher: impl Fn(&str, &str) -> bool,
+    ) -> Result<usize, TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| Ok(tier.canonicalize_labels(canonical, matcher)))
     }
 
-    /// Adjusts the bounds of the TextGrid and all tiers.
+    /// Inserts an interval into a tier, splitting any intervals it overlaps, with undo support.
     ///
     /// # Arguments
-    /// * `new_xmin` - New start time.
-    /// * `new_xmax` - New end time.
+    /// * `tier_name` - Name of the tier.
+    /// * `interval` - The interval to insert.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the new bounds are invalid or don't encompass all data.
-    pub fn adjust_bounds(&mut self, new_xmin: f64, new_xmax: f64) -> Result<(), TextGridError> {
-        if new_xmin >= new_xmax {
-            return Err(TextGridError::Format("New xmin must be less than xmax".into()));
-        }
-        for tier in &self.tiers {
-            if !tier.intervals.is_empty() && (tier.intervals[0].xmin < new_xmin || tier.intervals.last().unwrap().xmax > new_xmax) {
-                return Err(TextGridError::Format("New bounds must encompass all tier data".into()));
-            }
-            if !tier.points.is_empty() && (tier.points[0].time < new_xmin || tier.points.last().unwrap().time > new_xmax) {
-                return Err(TextGridError::Format("New bounds must encompass all tier data".into()));
-            }
-        }
-        self.save_change(Change::AdjustBounds(self.xmin, self.xmax));
-        self.xmin = new_xmin;
-        self.xmax = new_xmax;
-        for tier in &mut self.tiers {
-            tier.xmin = new_xmin;
-            tier.xmax = new_xmax;
-        }
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the interval cannot be inserted.
+    pub fn tier_insert_interval_splitting(&mut self, tier_name: &str, interval: Interval) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.insert_interval_splitting(interval))
     }
 
-    /// Inserts a silent interval into an IntervalTier.
+    /// Moves interval boundaries onto the nearest point of a point tier, with undo support.
+    ///
+    /// Only boundaries shared by two adjacent intervals are moved, and both intervals follow the
+    /// boundary, so the tier stays gapless. A boundary is left alone if no point lies within
+    /// `max_distance` or if moving it would empty one of its intervals.
     ///
     /// # Arguments
-    /// * `tier_name` - Name of the tier to modify.
-    /// * `start` - Start time of the silence.
-    /// * `end` - End time of the silence.
+    /// * `interval_tier` - Name of the IntervalTier whose boundaries move.
+    /// * `point_tier` - Name of the PointTier providing target times.
+    /// * `max_distance` - Maximum distance a boundary may move.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found, not an IntervalTier, or bounds are invalid.
-    pub fn insert_silence(&mut self, tier_name: &str, start: f64, end: f64) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        if tier.tier_type != TierType::IntervalTier {
-            return Err(TextGridError::Format("Can only insert silence in IntervalTier".into()));
-        }
-        if start < tier.xmin || end > tier.xmax || start >= end {
-            return Err(TextGridError::Format("Invalid silence bounds".into()));
+    /// Returns the number of boundaries moved, or a `TextGridError` if either tier is not found or has the wrong type.
+    pub fn snap_boundaries_to_points(&mut self, interval_tier: &str, point_tier: &str, max_distance: f64) -> Result<usize, TextGridError> {
+        let points = self.get_tier(point_tier).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if points.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format("Snap targets must come from a PointTier".into()));
         }
-
-        let before = tier.intervals.clone();
-        let mut new_intervals = Vec::new();
-        for interval in &before {
-            if interval.xmax <= start || interval.xmin >= end {
-                new_intervals.push(interval.clone());
-            } else {
-                if interval.xmin < start {
-                    new_intervals.push(Interval { xmin: interval.xmin, xmax: start, text: interval.text.clone() });
+        let times: Vec<f64> = points.points.iter().map(|p| p.time).collect();
+        self.edit_tier_intervals(interval_tier, |tier| {
+            if tier.tier_type != TierType::IntervalTier {
+                return Err(TextGridError::Format("Can only snap boundaries in an IntervalTier".into()));
+            }
+            let mut moved = 0;
+            for i in 1..tier.intervals.len() {
+                let boundary = tier.intervals[i].xmin;
+                if (tier.intervals[i - 1].xmax - boundary).abs() > BOUNDARY_EPSILON {
+                    continue;
                 }
-                if interval.xmax > end {
-                    new_intervals.push(Interval { xmin: end, xmax: interval.xmax, text: interval.text.clone() });
+                let index = times.partition_point(|&t| t < boundary);
+                let nearest = [index.checked_sub(1), Some(index)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|j| times.get(j).copied())
+                    .min_by(|a, b| (a - boundary).abs().total_cmp(&(b - boundary).abs()));
+                if let Some(time) = nearest
+                    && (time - boundary).abs() <= max_distance
+                    && time != boundary
+                    && time > tier.intervals[i - 1].xmin
+                    && time < tier.intervals[i].xmax
+                {
+                    tier.intervals[i - 1].xmax = time;
+                    tier.intervals[i].xmin = time;
+                    moved += 1;
                 }
             }
-        }
-        new_intervals.push(Interval { xmin: start, xmax: end, text: "".to_string() });
-        tier.intervals = new_intervals.clone();
-        tier.sort_intervals();
-        self.save_change(Change::InsertSilence(tier_name.to_string(), before, new_intervals));
-        Ok(())
+            Ok(moved)
+        })
     }
 
-    /// Queries all tiers for intervals containing the specified time.
+    /// Measures how far each point lies from the nearest interval boundary, for alignment QA.
+    ///
+    /// Boundaries are the start and end times of every interval in the IntervalTier. If the tier has
+    /// no intervals, every distance is `f64::INFINITY`.
     ///
     /// # Arguments
-    /// * `time` - Time to search for.
+    /// * `point_tier` - Name of the PointTier whose points are checked.
+    /// * `interval_tier` - Name of the IntervalTier providing the boundaries.
     ///
     /// # Returns
-    /// Returns a vector of tuples containing tiers and their matching intervals.
-    pub fn query_intervals_by_time(&self, time: f64) -> Vec<(&Tier, Vec<&Interval>)> {
-        self.tiers.iter().map(|t| (t, t.find_intervals_by_time(time))).filter(|(_, v)| !v.is_empty()).collect()
+    /// Returns `(point_time, distance_to_nearest_boundary)` for each point in order, or a `TextGridError`
+    /// if either tier is not found or has the wrong type.
+    pub fn point_boundary_deviations(&self, point_tier: &str, interval_tier: &str) -> Result<Vec<(f64, f64)>, TextGridError> {
+        let points = self.get_tier(point_tier).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if points.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format(format!("Tier '{}' is not a PointTier", point_tier)));
+        }
+        let intervals = self.get_tier(interval_tier).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if intervals.tier_type != TierType::IntervalTier {
+            return Err(TextGridError::Format(format!("Tier '{}' is not an IntervalTier", interval_tier)));
+        }
+        let mut boundaries: Vec<f64> = intervals.intervals.iter().flat_map(|i| [i.xmin, i.xmax]).collect();
+        boundaries.sort_by(f64::total_cmp);
+        boundaries.dedup();
+
+        Ok(points
+            .points
+            .iter()
+            .map(|point| {
+                let index = boundaries.partition_point(|&b| b < point.time);
+                let distance = [index.checked_sub(1), Some(index)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|j| boundaries.get(j))
+                    .map(|b| (b - point.time).abs())
+                    .fold(f64::INFINITY, f64::min);
+                (point.time, distance)
+            })
+            .collect())
     }
 
-    /// Queries all tiers for points at the specified time.
+    /// Appends the marks of a PointTier to the labels of the intervals containing them, with undo support.
+    ///
+    /// Points are matched with half-open lookup (`xmin <= time < xmax`) and appended in time order.
+    /// Blank marks and points outside every interval are ignored; an empty label becomes the mark itself.
     ///
     /// # Arguments
-    /// * `time` - Time to search for.
+    /// * `interval_tier` - Name of the IntervalTier whose labels are extended.
+    /// * `point_tier` - Name of the PointTier providing the marks.
+    /// * `separator` - String placed between the existing label and each appended mark.
     ///
     /// # Returns
-    /// Returns a vector of tuples containing tiers and their matching points.
-    pub fn query_points_by_time(&self, time: f64) -> Vec<(&Tier, Vec<&Point>)> {
-        self.tiers.iter().map(|t| (t, t.find_points_by_time(time))).filter(|(_, v)| !v.is_empty()).collect()
+    /// Returns the number of intervals annotated, or a `TextGridError` if either tier is not found or has the wrong type.
+    pub fn annotate_intervals_with_points(&mut self, interval_tier: &str, point_tier: &str, separator: &str) -> Result<usize, TextGridError> {
+        let points = self.get_tier(point_tier).ok_or(TextGridError::Format("Tier not found".into()))?;
+        if points.tier_type != TierType::PointTier {
+            return Err(TextGridError::Format("Annotations must come from a PointTier".into()));
+        }
+        let points = points.points.clone();
+        self.edit_tier_intervals(interval_tier, |tier| {
+            if tier.tier_type != TierType::IntervalTier {
+                return Err(TextGridError::Format("Can only annotate intervals in an IntervalTier".into()));
+            }
+            let mut annotated = HashSet::new();
+            for point in points.iter().filter(|p| !is_blank(&p.mark)) {
+                let index = tier.intervals.partition_point(|i| i.xmin <= point.time);
+                if let Some(interval) = index.checked_sub(1).map(|i| &mut tier.intervals[i])
+                    && point.time < interval.xmax
+                {
+                    if !interval.text.is_empty() {
+                        interval.text.push_str(separator);
+                    }
+                    interval.text.push_str(&point.mark);
+                    annotated.insert(index);
+                }
+            }
+            Ok(annotated.len())
+        })
     }
 
-    /// Queries all tiers for intervals containing the specified text substring.
+    /// Stretches a tier's first and last intervals to the tier's bounds with undo support.
     ///
     /// # Arguments
-    /// * `text` - Substring to search for in interval texts.
+    /// * `tier_name` - Name of the tier.
     ///
     /// # Returns
-    /// Returns a vector of tuples containing tiers and their matching intervals.
-    pub fn query_intervals_by_text(&self, text: &str) -> Vec<(&Tier, Vec<&Interval>)> {
-        self.tiers.iter().map(|t| (t, t.find_intervals_by_text(text))).filter(|(_, v)| !v.is_empty()).collect()
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or cannot be extended.
+    pub fn tier_extend_to_bounds(&mut self, tier_name: &str) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.extend_to_bounds())
     }
 
-    /// Adds an interval to a tier with undo support.
+    /// Collapses a tier's intervals shorter than a minimum duration into a neighbor with undo support.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
-    /// * `interval` - Interval to add.
+    /// * `min_duration` - Intervals strictly shorter than this are collapsed.
+    /// * `into` - Which neighbor absorbs a short interval.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_add_interval(&mut self, tier_name: &str, interval: Interval) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        tier.add_interval(interval.clone())?;
-        self.save_change(Change::AddInterval(tier_name.to_string(), interval));
-        Ok(())
+    /// Returns the number of intervals removed, or a `TextGridError` if the tier is not found or not an IntervalTier.
+    pub fn tier_collapse_short_intervals(&mut self, tier_name: &str, min_duration: f64, into: CollapseTarget) -> Result<usize, TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.collapse_short_intervals(min_duration, into))
     }
 
-    /// Removes an interval from a tier with undo support.
+    /// Moves each point in a tier by a time-dependent offset with undo support.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
-    /// * `index` - Index of the interval to remove.
+    /// * `offset` - Function returning the offset to add to a point, given its current time.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_remove_interval(&mut self, tier_name: &str, index: usize) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        let interval = tier.remove_interval(index)?;
-        self.save_change(Change::RemoveInterval(tier_name.to_string(), index, interval));
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the adjusted points are invalid.
+    pub fn tier_adjust_points<F: Fn(f64) -> f64>(&mut self, tier_name: &str, offset: F) -> Result<(), TextGridError> {
+        self.edit_tier_points(tier_name, |tier| tier.adjust_points(offset))
     }
 
-    /// Adds a point to a tier with undo support.
+    /// Sets an interval's bounds and moves its shared neighbor boundaries, with undo support.
+    ///
+    /// See [`Tier::resize_interval`] for how neighbors are adjusted.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
-    /// * `point` - Point to add.
+    /// * `index` - Index of the interval to resize.
+    /// * `new_xmin` - New start time of the interval.
+    /// * `new_xmax` - New end time of the interval.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_add_point(&mut self, tier_name: &str, point: Point) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        tier.add_point(point.clone())?;
-        self.save_change(Change::AddPoint(tier_name.to_string(), point));
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the resize is invalid.
+    pub fn tier_resize_interval(&mut self, tier_name: &str, index: usize, new_xmin: f64, new_xmax: f64) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.resize_interval(index, new_xmin, new_xmax))
     }
 
-    /// Removes a point from a tier with undo support.
+    /// Grows or shrinks every interval in a tier by `delta` with undo support.
+    ///
+    /// See [`Tier::pad_intervals`] for how boundaries are clamped.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
-    /// * `index` - Index of the point to remove.
+    /// * `delta` - Amount in seconds to move each boundary outward (inward if negative).
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_remove_point(&mut self, tier_name: &str, index: usize) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        let point = tier.remove_point(index)?;
-        self.save_change(Change::RemovePoint(tier_name.to_string(), index, point));
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the padding is invalid.
+    pub fn tier_pad_intervals(&mut self, tier_name: &str, delta: f64) -> Result<(), TextGridError> {
+        self.edit_tier_intervals(tier_name, |tier| tier.pad_intervals(delta))
     }
 
-    /// Splits an interval in a tier with undo support.
+    /// Numbers a tier's points in time order with undo support.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
-    /// * `index` - Index of the interval to split.
-    /// * `time` - Time at which to split the interval.
+    /// * `prefix` - Text placed before each number.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_split_interval(&mut self, tier_name: &str, index: usize, time: f64) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        let orig = tier.intervals[index].clone();
-        let (left, right) = tier.split_interval(index, time)?;
-        self.save_change(Change::SplitInterval(tier_name.to_string(), index, orig, left));
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found.
+    pub fn tier_number_points(&mut self, tier_name: &str, prefix: &str) -> Result<(), TextGridError> {
+        self.edit_tier_points(tier_name, |tier| {
+            tier.number_points(prefix);
+            Ok(())
+        })
     }
 
-    /// Merges intervals in a tier with undo support.
+    /// Moves a single point in a tier to a new time with undo support.
     ///
     /// # Arguments
     /// * `tier_name` - Name of the tier.
+    /// * `index` - Index of the point to move.
+    /// * `new_time` - The point's new time.
     ///
     /// # Returns
-    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or operation fails.
-    pub fn tier_merge_intervals(&mut self, tier_name: &str) -> Result<(), TextGridError> {
-        let tier = self.get_tier_mut(tier_name).ok_or(TextGridError::Format("Tier not found".into()))?;
-        let before = tier.merge_intervals()?;
-        let after = tier.intervals.clone();
-        self.save_change(Change::MergeIntervals(tier_name.to_string(), before, after));
-        Ok(())
+    /// Returns `Ok(())` on success or a `TextGridError` if the tier is not found or the move is invalid.
+    pub fn tier_move_point(&mut self, tier_name: &str, index: usize, new_time: f64) -> Result<(), TextGridError> {
+        self.edit_tier_points(tier_name, |tier| tier.move_point(index, new_time))
     }
 }
 
@@ -878,4 +3986,214 @@ impl TextGrid {
         self.tiers = tiers;
         self
     }
-}
\ No newline at end of file
+
+    /// Gets read-only access to the tiers.
+    ///
+    /// # Returns
+    /// Returns a slice of the TextGrid's tiers.
+    pub fn tiers(&self) -> &[Tier] {
+        &self.tiers
+    }
+
+    /// Returns owned copies of the tiers, independent of the TextGrid's lifetime.
+    ///
+    /// # Returns
+    /// Returns a vector of cloned tiers.
+    pub fn tiers_cloned(&self) -> Vec<Tier> {
+        self.tiers.clone()
+    }
+
+    /// Gets guarded mutable access to the tiers.
+    ///
+    /// Edits made through the returned guard bypass the per-operation checks, so when the guard is
+    /// dropped every tier's intervals and points are re-sorted by time and the whole edit is recorded
+    /// as a single undoable change.
+    ///
+    /// Dropping the guard does **not** validate the result, since `Drop` cannot report errors. Call
+    /// [`TiersMut::finish`] to end the edit with validation, and undo it if that fails.
+    ///
+    /// # Returns
+    /// Returns a `TiersMut` guard dereferencing to the tier vector.
+    ///
+    /// # Examples
+    /// The tiers are not a public field, so edits cannot skip the guard:
+    /// ```rust,compile_fail
+    /// let mut tg = textgrid::TextGrid::new(0.0, 1.0).unwrap();
+    /// tg.tiers.clear();
+    /// ```
+    pub fn tiers_mut(&mut self) -> TiersMut<'_> {
+        let before = self.tiers.clone();
+        TiersMut { textgrid: self, before: Some(before) }
+    }
+}
+
+/// Consumes the TextGrid, yielding its tiers in order. History is discarded.
+impl IntoIterator for TextGrid {
+    type Item = Tier;
+    type IntoIter = std::vec::IntoIter<Tier>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiers.into_iter()
+    }
+}
+
+/// Iterates over the TextGrid's tiers by reference.
+impl<'a> IntoIterator for &'a TextGrid {
+    type Item = &'a Tier;
+    type IntoIter = std::slice::Iter<'a, Tier>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiers.iter()
+    }
+}
+
+/// Builder that accumulates tiers and validates the resulting TextGrid once.
+///
+/// Unlike repeated [`TextGrid::add_tier`] calls, building records no undo history.
+///
+/// # Examples
+/// ```rust
+/// use textgrid::{TextGridBuilder, Tier, TierType};
+///
+/// let tier = Tier::new("words", TierType::IntervalTier, 0.0, 1.0).unwrap();
+/// let tg = TextGridBuilder::new().bounds(0.0, 1.0).tier(tier).build().unwrap();
+/// assert_eq!(tg.tiers().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextGridBuilder {
+    /// Explicit TextGrid bounds, if set.
+    bounds: Option<(f64, f64)>,
+    /// Tiers to include, in order.
+    tiers: Vec<Tier>,
+    /// Whether tiers may share a name.
+    allow_duplicate_tier_names: bool,
+}
+
+impl TextGridBuilder {
+    /// Creates an empty builder.
+    ///
+    /// # Returns
+    /// Returns a builder with no bounds and no tiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the bounds of the TextGrid.
+    ///
+    /// # Arguments
+    /// * `xmin` - Start time of the TextGrid.
+    /// * `xmax` - End time of the TextGrid.
+    ///
+    /// # Returns
+    /// Returns the builder.
+    pub fn bounds(mut self, xmin: f64, xmax: f64) -> Self {
+        self.bounds = Some((xmin, xmax));
+        self
+    }
+
+    /// Appends a tier.
+    ///
+    /// # Arguments
+    /// * `tier` - The tier to append.
+    ///
+    /// # Returns
+    /// Returns the builder.
+    pub fn tier(mut self, tier: Tier) -> Self {
+        self.tiers.push(tier);
+        self
+    }
+
+    /// Sets whether tiers may share a name, both when building and in later edits of the built TextGrid.
+    ///
+    /// # Arguments
+    /// * `allow` - If `true`, duplicate tier names are accepted.
+    ///
+    /// # Returns
+    /// Returns the builder.
+    pub fn allow_duplicate_tier_names(mut self, allow: bool) -> Self {
+        self.allow_duplicate_tier_names = allow;
+        self
+    }
+
+    /// Builds and validates the TextGrid.
+    ///
+    /// If no bounds were set, they are taken from the earliest tier start and the latest tier end.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the validated `TextGrid`, with empty history, or a `TextGridError`.
+    ///
+    /// # Errors
+    /// - `TextGridError::Format` if no bounds were set and there are no tiers, or if the TextGrid fails validation.
+    pub fn build(self) -> Result<TextGrid, TextGridError> {
+        let (xmin, xmax) = match self.bounds {
+            Some(bounds) => bounds,
+            None if !self.tiers.is_empty() => (
+                self.tiers.iter().map(|t| t.xmin).fold(f64::INFINITY, f64::min),
+                self.tiers.iter().map(|t| t.xmax).fold(f64::NEG_INFINITY, f64::max),
+            ),
+            None => return Err(TextGridError::Format("TextGrid bounds not set".into())),
+        };
+        let mut textgrid = TextGrid::new(xmin, xmax)?.with_tiers(self.tiers);
+        textgrid.set_allow_duplicate_tier_names(self.allow_duplicate_tier_names);
+        crate::validator::validate_textgrid_with(&textgrid, &textgrid.validation_options())?;
+        Ok(textgrid)
+    }
+}
+
+/// Guard providing mutable access to a TextGrid's tiers, restoring invariants when dropped.
+///
+/// Created by [`TextGrid::tiers_mut`]. Dropping the guard re-sorts the tiers but skips validation;
+/// only [`TiersMut::finish`] validates.
+#[derive(Debug)]
+pub struct TiersMut<'a> {
+    /// The TextGrid being edited.
+    textgrid: &'a mut TextGrid,
+    /// Tiers as they were before the edit, taken once the edit is committed.
+    before: Option<Vec<Tier>>,
+}
+
+impl TiersMut<'_> {
+    /// Ends the edit, re-sorting the tiers and validating the resulting TextGrid.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the edited TextGrid is valid or a `TextGridError` describing the first problem.
+    /// The edit is kept (and can be undone) either way.
+    pub fn finish(mut self) -> Result<(), TextGridError> {
+        self.commit();
+        crate::validator::validate_textgrid_with(self.textgrid, &self.textgrid.validation_options())
+    }
+
+    /// Re-sorts every tier and records the edit for undo, once, if the tiers changed.
+    fn commit(&mut self) {
+        if let Some(before) = self.before.take() {
+            for tier in &mut self.textgrid.tiers {
+                tier.sort_intervals();
+                tier.sort_points();
+            }
+            if before != self.textgrid.tiers {
+                let (xmin, xmax) = (self.textgrid.xmin, self.textgrid.xmax);
+                self.textgrid.save_change(Change::ReplaceGrid(xmin, xmax, before));
+            }
+        }
+    }
+}
+
+impl Deref for TiersMut<'_> {
+    type Target = Vec<Tier>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.textgrid.tiers
+    }
+}
+
+impl DerefMut for TiersMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.textgrid.tiers
+    }
+}
+
+impl Drop for TiersMut<'_> {
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
@@ -6,9 +6,21 @@
 //!
 //! ## Validation Checks
 //! - **TextGrid Bounds**: Ensures `xmin < xmax`.
+//! - **Tier Names**: Rejects two tiers with the same name, since lookups by name only find the first.
 //! - **Tier Bounds**: Verifies each tier's bounds are within the TextGrid's bounds and `xmin < xmax`.
 //! - **IntervalTiers**: Confirms intervals are non-overlapping, sequential, and have valid bounds (`xmin < xmax`).
-//! - **PointTiers**: Ensures all points fall within the tier's time bounds.
+//! - **PointTiers**: Ensures all points are sorted and fall within the tier's time bounds.
+//!
+//! `validate_textgrid_with` accepts `ValidationOptions` to add checks (gapless tiers, tier bounds
+//! matching the TextGrid) or relax them (overlap tolerance, zero-length intervals, duplicate tier names).
+//!
+//! ## Repairs
+//! Lenient loading (`TextGrid::from_file_lenient`) first applies recoverable fixes and reports each one:
+//! - TextGrid bounds are widened to cover every tier.
+//! - Unsorted intervals and points are sorted.
+//! - Intervals reaching outside their tier are clipped (and dropped if nothing remains).
+//! - Points outside their tier are clamped to the nearest tier bound.
+//! - A tier whose name is already used by an earlier tier is renamed (`words` becomes `words (2)`).
 //!
 //! ## Usage
 //! ```rust
@@ -17,18 +29,13 @@
 //! fn main() -> Result<(), textgrid::TextGridError> {
 //!     // Create a valid TextGrid
 //!     let mut tg = TextGrid::new(0.0, 10.0)?;
-//!     let tier = Tier {
-//!         name: "words".to_string(),
-//!         tier_type: TierType::IntervalTier,
-//!         xmin: 0.0,
-//!         xmax: 10.0,
-//!         intervals: vec![Interval {
+//!     let tier = Tier::new("words", TierType::IntervalTier, 0.0, 10.0)?
+//!         .with_intervals(vec![Interval {
 //!             xmin: 1.0,
 //!             xmax: 2.0,
 //!             text: "hello".to_string(),
-//!         }],
-//!         points: vec![],
-//!     };
+//!             score: None,
+//!         }]);
 //!     tg.add_tier(tier)?;
 //!
 //!     // Validate the TextGrid
@@ -38,7 +45,39 @@
 //! }
 //! ```
 
-use crate::types::{TextGrid, TextGridError, TierType};
+use crate::types::{TextGrid, TextGridError, Tier, TierType};
+
+/// Options controlling which checks [`validate_textgrid_with`] performs.
+///
+/// The default options match [`validate_textgrid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValidationOptions {
+    /// Amount by which an interval may start before the previous one ends without counting as an overlap.
+    /// Also used as the tolerance for `require_gapless`. Defaults to `0.0`.
+    pub overlap_epsilon: f64,
+    /// If `true`, IntervalTiers must cover their bounds exactly, with no gaps between intervals. Defaults to `false`.
+    pub require_gapless: bool,
+    /// If `false`, two tiers with the same name are an error. Defaults to `false`; Praat itself allows
+    /// duplicates, but `get_tier` and every by-name edit only ever reach the first of them. Loading
+    /// and writing files always allows them, so any Praat file can be read and written back.
+    pub allow_duplicate_tier_names: bool,
+    /// If `true`, every tier's bounds must equal the TextGrid's bounds. Defaults to `false`.
+    pub check_tier_bounds_match: bool,
+    /// If `true`, intervals with `xmin == xmax` are an error. Defaults to `true`.
+    pub forbid_zero_duration: bool,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            overlap_epsilon: 0.0,
+            require_gapless: false,
+            allow_duplicate_tier_names: false,
+            check_tier_bounds_match: false,
+            forbid_zero_duration: true,
+        }
+    }
+}
 
 /// Validates the integrity of a `TextGrid` structure.
 ///
@@ -51,52 +90,208 @@ use crate::types::{TextGrid, TextGridError, TierType};
 /// # Errors
 /// - `TextGridError::Format` if any of the following conditions are met:
 ///   - TextGrid `xmin >= xmax`.
+///   - Two tiers share a name.
 ///   - Tier bounds are outside TextGrid bounds or `xmin >= xmax`.
 ///   - IntervalTiers have overlapping or invalid intervals (`xmin >= xmax`).
 ///   - PointTiers have points outside tier bounds.
 ///
 /// # Examples
 /// ```rust
+/// use textgrid::TextGrid;
+///
 /// let tg = TextGrid::new(0.0, 5.0).unwrap(); // Assume valid tiers are added
 /// assert!(textgrid::validate_textgrid(&tg).is_ok());
 /// ```
 pub fn validate_textgrid(textgrid: &TextGrid) -> Result<(), TextGridError> {
+    validate_textgrid_with(textgrid, &ValidationOptions::default())
+}
+
+/// Validates the integrity of a `TextGrid` structure with configurable checks.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to validate.
+/// * `options` - Which optional checks to perform and with what tolerance.
+///
+/// # Returns
+/// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError` if validation fails.
+///
+/// # Errors
+/// - `TextGridError::Format` for any of the [`validate_textgrid`] conditions (as relaxed by `options`), or if:
+///   - `require_gapless` is set and an IntervalTier has a gap.
+///   - `check_tier_bounds_match` is set and a tier's bounds differ from the TextGrid's.
+///
+/// # Examples
+/// ```rust
+/// use textgrid::{TextGrid, ValidationOptions, validate_textgrid_with};
+///
+/// let tg = TextGrid::new(0.0, 5.0).unwrap();
+/// let options = ValidationOptions { require_gapless: true, ..ValidationOptions::default() };
+/// assert!(validate_textgrid_with(&tg, &options).is_ok());
+/// ```
+pub fn validate_textgrid_with(textgrid: &TextGrid, options: &ValidationOptions) -> Result<(), TextGridError> {
     if textgrid.xmin >= textgrid.xmax {
         return Err(TextGridError::Format("TextGrid xmin must be less than xmax".into()));
     }
 
-    for tier in &textgrid.tiers {
+    for (i, tier) in textgrid.tiers.iter().enumerate() {
         if tier.xmin < textgrid.xmin || tier.xmax > textgrid.xmax {
             return Err(TextGridError::Format("Tier bounds must be within TextGrid bounds".into()));
         }
-        if tier.xmin >= tier.xmax {
-            return Err(TextGridError::Format("Tier xmin must be less than xmax".into()));
+        if options.check_tier_bounds_match && (tier.xmin != textgrid.xmin || tier.xmax != textgrid.xmax) {
+            return Err(TextGridError::Format(format!("Tier '{}' bounds must match TextGrid bounds", tier.name)));
         }
+        if !options.allow_duplicate_tier_names && textgrid.tiers[..i].iter().any(|t| t.name == tier.name) {
+            return Err(TextGridError::Format(format!("Duplicate tier name '{}'", tier.name)));
+        }
+        validate_tier_with(tier, options)?;
+    }
+    Ok(())
+}
+
+/// Validates the integrity of a single `Tier`, independent of any TextGrid.
+///
+/// # Arguments
+/// * `tier` - The `Tier` to validate.
+///
+/// # Returns
+/// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError` if validation fails.
+///
+/// # Errors
+/// - `TextGridError::Format` if any of the following conditions are met:
+///   - Tier `xmin >= xmax`.
+///   - IntervalTiers have overlapping, unordered, out-of-bounds, or invalid intervals (`xmin >= xmax`).
+///   - PointTiers have unordered points or points outside tier bounds.
+pub fn validate_tier(tier: &Tier) -> Result<(), TextGridError> {
+    validate_tier_with(tier, &ValidationOptions::default())
+}
+
+/// Validates a single `Tier` with the tier-level checks of `options`.
+fn validate_tier_with(tier: &Tier, options: &ValidationOptions) -> Result<(), TextGridError> {
+    if tier.xmin >= tier.xmax {
+        return Err(TextGridError::Format("Tier xmin must be less than xmax".into()));
+    }
 
-        match tier.tier_type {
-            TierType::IntervalTier => {
-                if tier.intervals.is_empty() {
-                    continue;
+    match tier.tier_type {
+        TierType::IntervalTier => {
+            let epsilon = options.overlap_epsilon;
+            let mut prev_xmax = tier.xmin;
+            for interval in &tier.intervals {
+                if interval.xmin < prev_xmax - epsilon {
+                    return Err(TextGridError::Format("Overlapping intervals detected".into()));
+                }
+                if options.require_gapless && interval.xmin > prev_xmax + epsilon {
+                    return Err(TextGridError::Format(format!("Tier '{}' has a gap at {}", tier.name, prev_xmax)));
                 }
-                let mut prev_xmax = tier.xmin;
-                for interval in &tier.intervals {
-                    if interval.xmin < prev_xmax {
-                        return Err(TextGridError::Format("Overlapping intervals detected".into()));
-                    }
-                    if interval.xmin >= interval.xmax {
-                        return Err(TextGridError::Format("Interval xmin must be less than xmax".into()));
-                    }
-                    prev_xmax = interval.xmax;
+                if interval.xmin > interval.xmax || (options.forbid_zero_duration && interval.xmin == interval.xmax) {
+                    return Err(TextGridError::Format("Interval xmin must be less than xmax".into()));
                 }
+                prev_xmax = interval.xmax;
+            }
+            if prev_xmax > tier.xmax {
+                return Err(TextGridError::Format("Interval out of tier bounds".into()));
+            }
+            if options.require_gapless && prev_xmax < tier.xmax - epsilon {
+                return Err(TextGridError::Format(format!("Tier '{}' has a gap at {}", tier.name, prev_xmax)));
             }
-            TierType::PointTier => {
-                for point in &tier.points {
-                    if point.time < tier.xmin || point.time > tier.xmax {
-                        return Err(TextGridError::Format("Point time out of tier bounds".into()));
-                    }
+        }
+        TierType::PointTier => {
+            let mut prev_time = tier.xmin;
+            for point in &tier.points {
+                if point.time < tier.xmin || point.time > tier.xmax {
+                    return Err(TextGridError::Format("Point time out of tier bounds".into()));
+                }
+                if point.time < prev_time {
+                    return Err(TextGridError::Format("Points must be sorted by time".into()));
                 }
+                prev_time = point.time;
             }
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Applies recoverable fixes to a `TextGrid`, describing each fix applied.
+///
+/// Problems that cannot be fixed without guessing (e.g. overlapping intervals) are left for
+/// [`validate_textgrid`] to report.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to repair in place.
+///
+/// # Returns
+/// Returns a human-readable warning for each fix, in the order they were applied.
+pub(crate) fn repair_textgrid(textgrid: &mut TextGrid) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    let tiers_xmin = textgrid.tiers.iter().map(|t| t.xmin).fold(textgrid.xmin, f64::min);
+    let tiers_xmax = textgrid.tiers.iter().map(|t| t.xmax).fold(textgrid.xmax, f64::max);
+    if tiers_xmin < textgrid.xmin || tiers_xmax > textgrid.xmax {
+        warnings.push(format!(
+            "TextGrid bounds [{}, {}] widened to [{}, {}] to cover all tiers",
+            textgrid.xmin, textgrid.xmax, tiers_xmin, tiers_xmax
+        ));
+        textgrid.xmin = tiers_xmin;
+        textgrid.xmax = tiers_xmax;
+    }
+
+    for tier in &mut textgrid.tiers {
+        let (xmin, xmax) = (tier.xmin, tier.xmax);
+        if xmin >= xmax || xmin.is_nan() || xmax.is_nan() {
+            continue;
+        }
+        if tier.intervals.windows(2).any(|pair| pair[0].xmin > pair[1].xmin) {
+            tier.intervals.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
+            warnings.push(format!("Tier '{}': intervals sorted by start time", tier.name));
+        }
+        tier.intervals.retain_mut(|interval| {
+            if interval.xmin >= xmin && interval.xmax <= xmax {
+                return true;
+            }
+            let (start, end) = (interval.xmin.max(xmin), interval.xmax.min(xmax));
+            if start < end {
+                warnings.push(format!(
+                    "Tier '{}': interval [{}, {}] clipped to tier bounds [{}, {}]",
+                    tier.name, interval.xmin, interval.xmax, xmin, xmax
+                ));
+                interval.xmin = start;
+                interval.xmax = end;
+                true
+            } else {
+                warnings.push(format!(
+                    "Tier '{}': interval [{}, {}] outside tier bounds [{}, {}] dropped",
+                    tier.name, interval.xmin, interval.xmax, xmin, xmax
+                ));
+                false
+            }
+        });
+
+        for point in &mut tier.points {
+            if point.time < xmin || point.time > xmax {
+                let clamped = point.time.clamp(xmin, xmax);
+                warnings.push(format!(
+                    "Tier '{}': point at {} clamped to {}",
+                    tier.name, point.time, clamped
+                ));
+                point.time = clamped;
+            }
+        }
+        if tier.points.windows(2).any(|pair| pair[0].time > pair[1].time) {
+            tier.points.sort_by(|a, b| a.time.total_cmp(&b.time));
+            warnings.push(format!("Tier '{}': points sorted by time", tier.name));
+        }
+    }
+
+    for i in 1..textgrid.tiers.len() {
+        let name = &textgrid.tiers[i].name;
+        if !textgrid.tiers[..i].iter().any(|t| &t.name == name) {
+            continue;
+        }
+        let renamed = (2..)
+            .map(|n| format!("{} ({})", name, n))
+            .find(|candidate| textgrid.tiers.iter().all(|t| &t.name != candidate))
+            .expect("an unused tier name");
+        warnings.push(format!("Tier '{}' renamed to '{}' because the name is already used", name, renamed));
+        textgrid.tiers[i].name = renamed;
+    }
+    warnings
+}
@@ -0,0 +1,91 @@
+//! WebVTT subtitle export for TextGrid data.
+//!
+//! This module turns one IntervalTier into a WebVTT file, so time-aligned transcriptions can be used
+//! directly as captions.
+//!
+//! ## Format
+//! - The file starts with a `WEBVTT` header; each cue is preceded by a blank line.
+//! - Each non-empty interval becomes one cue, in time order; blank intervals are skipped.
+//! - Times are written as `HH:MM:SS.mmm`, rounded to the nearest millisecond. Hours are not capped at 99.
+//! - WebVTT requires a cue to end after it starts, so intervals that round to zero length are skipped.
+//! - `&`, `<` and `>` in labels are escaped, and blank lines inside a label are dropped so they
+//!   cannot end the cue early.
+//!
+//! ## Usage
+//! ```rust
+//! use textgrid::{TextGrid, Tier, TierType, Interval};
+//!
+//! fn main() -> Result<(), textgrid::TextGridError> {
+//!     let mut tg = TextGrid::new(0.0, 2.0)?;
+//!     tg.add_tier(Tier::new("words", TierType::IntervalTier, 0.0, 2.0)?
+//!         .with_intervals(vec![Interval { xmin: 0.5, xmax: 1.25, text: "hello".to_string(), score: None }]))?;
+//!     assert_eq!(tg.to_webvtt("words")?, "WEBVTT\n\n00:00:00.500 --> 00:00:01.250\nhello\n");
+//!     Ok(())
+//! }
+//! ```
+
+use crate::types::{TextGridError, Tier, TierType};
+
+/// Writes an IntervalTier as a WebVTT file.
+///
+/// # Arguments
+/// * `tier` - The IntervalTier to export.
+///
+/// # Returns
+/// Returns a `Result` containing the WebVTT text or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::Format` if the tier is not an IntervalTier or a cue time is negative or not finite.
+pub(crate) fn write_webvtt(tier: &Tier) -> Result<String, TextGridError> {
+    if tier.tier_type != TierType::IntervalTier {
+        return Err(TextGridError::Format("WebVTT export requires an IntervalTier".into()));
+    }
+    let mut cues: Vec<_> = tier.intervals.iter().filter(|i| !i.text.trim().is_empty()).collect();
+    cues.sort_by(|a, b| a.xmin.total_cmp(&b.xmin));
+
+    let mut vtt = String::from("WEBVTT\n");
+    for cue in cues {
+        let (start, end) = (to_millis(cue.xmin)?, to_millis(cue.xmax)?);
+        if end <= start {
+            continue;
+        }
+        vtt.push_str(&format!(
+            "\n{} --> {}\n{}\n",
+            format_timestamp(start),
+            format_timestamp(end),
+            escape_text(&cue.text)
+        ));
+    }
+    Ok(vtt)
+}
+
+/// Converts seconds to whole milliseconds, rounding to the nearest one.
+///
+/// # Errors
+/// - `TextGridError::Format` if `seconds` is negative or not finite.
+fn to_millis(seconds: f64) -> Result<u64, TextGridError> {
+    if seconds < 0.0 || !seconds.is_finite() {
+        return Err(TextGridError::Format(format!("Cannot write time {} as a WebVTT timestamp", seconds)));
+    }
+    Ok((seconds * 1000.0).round() as u64)
+}
+
+/// Formats milliseconds as a WebVTT timestamp (`HH:MM:SS.mmm`).
+fn format_timestamp(millis: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// Escapes cue text and drops blank lines, which would otherwise terminate the cue.
+fn escape_text(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
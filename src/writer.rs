@@ -8,25 +8,23 @@
 //! - **Long Format**: Verbose format with labeled fields (e.g., `xmin = 0`).
 //! - **Short Format**: Compact format with bare values (e.g., `0` instead of `xmin = 0`).
 //!
+//! Long-format output can optionally carry each tier's name as a Praat `!` comment on its
+//! `item [n]:` line (see `TextWriteOptions`), which helps when diffing generated files.
+//!
 //! ## Usage
-//! ```rust
+//! ```rust,no_run
 //! use textgrid::{TextGrid, Tier, TierType, Interval, write_textgrid};
 //!
 //! fn main() -> Result<(), textgrid::TextGridError> {
 //!     // Create a simple TextGrid
 //!     let mut tg = TextGrid::new(0.0, 10.0)?;
-//!     let tier = Tier {
-//!         name: "words".to_string(),
-//!         tier_type: TierType::IntervalTier,
-//!         xmin: 0.0,
-//!         xmax: 10.0,
-//!         intervals: vec![Interval {
+//!     let tier = Tier::new("words", TierType::IntervalTier, 0.0, 10.0)?
+//!         .with_intervals(vec![Interval {
 //!             xmin: 1.0,
 //!             xmax: 2.0,
 //!             text: "hello".to_string(),
-//!         }],
-//!         points: vec![],
-//!     };
+//!             score: None,
+//!         }]);
 //!     tg.add_tier(tier)?;
 //!
 //!     // Write to a file in long format
@@ -37,9 +35,17 @@
 
 use crate::types::{TextGrid, TextGridError, TierType};
 use std::fs::File;
-use std::io::{Write};
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
+/// Options controlling how a `TextGrid` is written in text format.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextWriteOptions {
+    /// If `true`, each long-format `item [n]:` line ends with a `! name` comment identifying the tier.
+    /// Praat and this crate's parser ignore the comment. Has no effect on the short format.
+    pub annotate_indices: bool,
+}
+
 /// Writes a `TextGrid` to a Praat `.TextGrid` file.
 ///
 /// # Arguments
@@ -54,32 +60,80 @@ use std::path::Path;
 /// - `TextGridError::IO` if the file cannot be created or written to.
 ///
 /// # Examples
-/// ```rust
+/// ```rust,no_run
+/// use textgrid::TextGrid;
+///
 /// let tg = TextGrid::new(0.0, 5.0).unwrap(); // Assume tiers are added
 /// textgrid::write_textgrid(&tg, "test.TextGrid", true).unwrap();
 /// ```
 pub fn write_textgrid<P: AsRef<Path>>(textgrid: &TextGrid, path: P, short_format: bool) -> Result<(), TextGridError> {
-    let mut file = File::create(path)?;
+    write_textgrid_with_options(textgrid, path, short_format, &TextWriteOptions::default())
+}
+
+/// Writes a `TextGrid` to a Praat `.TextGrid` file with explicit write options.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to write.
+/// * `path` - Path to the output file, implementing `AsRef<Path>`.
+/// * `short_format` - If `true`, writes in short format; otherwise, uses long format.
+/// * `options` - Text write options.
+///
+/// # Returns
+/// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::IO` if the file cannot be created or written to.
+pub fn write_textgrid_with_options<P: AsRef<Path>>(
+    textgrid: &TextGrid,
+    path: P,
+    short_format: bool,
+    options: &TextWriteOptions,
+) -> Result<(), TextGridError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_textgrid_to(textgrid, &mut writer, short_format, options)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes a `TextGrid` in text format to any writer, such as a buffer or a socket.
+///
+/// # Arguments
+/// * `textgrid` - The `TextGrid` to write.
+/// * `writer` - The destination to write to.
+/// * `short_format` - If `true`, writes in short format; otherwise, uses long format.
+/// * `options` - Text write options.
+///
+/// # Returns
+/// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
+///
+/// # Errors
+/// - `TextGridError::IO` if writing fails.
+pub fn write_textgrid_to<W: Write>(
+    textgrid: &TextGrid,
+    writer: &mut W,
+    short_format: bool,
+    options: &TextWriteOptions,
+) -> Result<(), TextGridError> {
     if short_format {
-        write_short_format(&mut file, textgrid)?;
+        write_short_format(writer, textgrid)
     } else {
-        write_long_format(&mut file, textgrid)?;
+        write_long_format(writer, textgrid, options)
     }
-    Ok(())
 }
 
 /// Writes a `TextGrid` to a file in the long (verbose) format.
 ///
 /// # Arguments
-/// * `file` - The file to write to.
+/// * `file` - The destination to write to.
 /// * `textgrid` - The `TextGrid` to write.
+/// * `options` - Text write options.
 ///
 /// # Returns
 /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
 ///
 /// # Errors
-/// - `TextGridError::IO` if writing to the file fails.
-fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGridError> {
+/// - `TextGridError::IO` if writing fails.
+fn write_long_format(file: &mut impl Write, textgrid: &TextGrid, options: &TextWriteOptions) -> Result<(), TextGridError> {
     writeln!(file, "File type = \"ooTextFile\"")?;
     writeln!(file, "Object class = \"TextGrid\"")?;
     writeln!(file, "xmin = {}", textgrid.xmin)?;
@@ -89,7 +143,13 @@ fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGri
     writeln!(file, "item []:")?;
 
     for (i, tier) in textgrid.tiers.iter().enumerate() {
-        writeln!(file, "    item [{}]:", i + 1)?;
+        if options.annotate_indices {
+            // The comment ends at the line break, so only the first line of a multi-line name fits in it.
+            let first_line = tier.name.split(['\n', '\r']).next().unwrap_or_default();
+            writeln!(file, "    item [{}]: ! {}", i + 1, first_line)?;
+        } else {
+            writeln!(file, "    item [{}]:", i + 1)?;
+        }
         writeln!(
             file,
             "        class = \"{}\"",
@@ -98,7 +158,7 @@ fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGri
                 TierType::PointTier => "TextTier",
             }
         )?;
-        writeln!(file, "        name = \"{}\"", tier.name)?;
+        writeln!(file, "        name = {}", quote(&tier.name))?;
         writeln!(file, "        xmin = {}", tier.xmin)?;
         writeln!(file, "        xmax = {}", tier.xmax)?;
         match tier.tier_type {
@@ -108,7 +168,7 @@ fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGri
                     writeln!(file, "        intervals [{}]:", j + 1)?;
                     writeln!(file, "            xmin = {}", interval.xmin)?;
                     writeln!(file, "            xmax = {}", interval.xmax)?;
-                    writeln!(file, "            text = \"{}\"", interval.text)?;
+                    writeln!(file, "            text = {}", quote(&interval.text))?;
                 }
             }
             TierType::PointTier => {
@@ -116,7 +176,7 @@ fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGri
                 for (j, point) in tier.points.iter().enumerate() {
                     writeln!(file, "        points [{}]:", j + 1)?;
                     writeln!(file, "            time = {}", point.time)?;
-                    writeln!(file, "            mark = \"{}\"", point.mark)?;
+                    writeln!(file, "            mark = {}", quote(&point.mark))?;
                 }
             }
         }
@@ -127,15 +187,15 @@ fn write_long_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGri
 /// Writes a `TextGrid` to a file in the short (compact) format.
 ///
 /// # Arguments
-/// * `file` - The file to write to.
+/// * `file` - The destination to write to.
 /// * `textgrid` - The `TextGrid` to write.
 ///
 /// # Returns
 /// Returns a `Result` indicating success (`Ok(())`) or a `TextGridError`.
 ///
 /// # Errors
-/// - `TextGridError::IO` if writing to the file fails.
-fn write_short_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGridError> {
+/// - `TextGridError::IO` if writing fails.
+fn write_short_format(file: &mut impl Write, textgrid: &TextGrid) -> Result<(), TextGridError> {
     writeln!(file, "File type = \"ooTextFile\"")?;
     writeln!(file, "Object class = \"TextGrid\"")?;
     writeln!(file, "{}", textgrid.xmin)?;
@@ -151,7 +211,7 @@ fn write_short_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGr
                 TierType::PointTier => "TextTier",
             }
         )?;
-        writeln!(file, "\"{}\"", tier.name)?;
+        writeln!(file, "{}", quote(&tier.name))?;
         writeln!(file, "{}", tier.xmin)?;
         writeln!(file, "{}", tier.xmax)?;
         match tier.tier_type {
@@ -160,17 +220,22 @@ fn write_short_format(file: &mut File, textgrid: &TextGrid) -> Result<(), TextGr
                 for interval in &tier.intervals {
                     writeln!(file, "{}", interval.xmin)?;
                     writeln!(file, "{}", interval.xmax)?;
-                    writeln!(file, "\"{}\"", interval.text)?;
+                    writeln!(file, "{}", quote(&interval.text))?;
                 }
             }
             TierType::PointTier => {
                 writeln!(file, "{}", tier.points.len())?;
                 for point in &tier.points {
                     writeln!(file, "{}", point.time)?;
-                    writeln!(file, "\"{}\"", point.mark)?;
+                    writeln!(file, "{}", quote(&point.mark))?;
                 }
             }
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Wraps a value in double quotes, doubling any embedded quotes as Praat does.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}